@@ -0,0 +1,91 @@
+//! Day crates are only linked into the binary when something references them, so emit an
+//! `extern crate` for every `aocYYYYdayDD` dependency in Cargo.toml to get their solutions
+//! into the registry.
+//!
+//! Also generates a regression test for every day that has an `answers.toml`.
+
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+fn is_day_crate(name: &str) -> bool {
+    let Some(rest) = name.strip_prefix("aoc") else {
+        return false;
+    };
+    let Some((year, day)) = rest.split_once("day") else {
+        return false;
+    };
+
+    year.len() == 4
+        && day.len() == 2
+        && year.chars().chain(day.chars()).all(|c| c.is_ascii_digit())
+}
+
+struct Day {
+    name: String,
+    year: u16,
+    day: u8,
+    path: PathBuf,
+}
+
+fn days() -> Vec<Day> {
+    let manifest: toml::Table = fs::read_to_string("Cargo.toml")
+        .expect("Cargo.toml is readable")
+        .parse()
+        .expect("Cargo.toml is valid");
+
+    manifest["dependencies"]
+        .as_table()
+        .expect("there are dependencies")
+        .iter()
+        .filter(|(name, _)| is_day_crate(name))
+        .map(|(name, dependency)| Day {
+            name: name.clone(),
+            year: name[3..7].parse().expect("checked by is_day_crate"),
+            day: name[10..12].parse().expect("checked by is_day_crate"),
+            path: dependency
+                .get("path")
+                .and_then(|p| p.as_str())
+                .map(PathBuf::from)
+                .unwrap_or_else(|| panic!("{name} is a path dependency")),
+        })
+        .collect()
+}
+
+/// `None` when the day has no answers file
+fn is_slow(answers: &Path) -> Option<bool> {
+    let answers: toml::Table = fs::read_to_string(answers).ok()?.parse().ok()?;
+    Some(answers.get("slow").and_then(|s| s.as_bool()).unwrap_or(false))
+}
+
+fn main() {
+    println!("cargo::rerun-if-changed=Cargo.toml");
+
+    let out = PathBuf::from(env::var("OUT_DIR").expect("set by cargo"));
+    let days = days();
+
+    let externs: String = days
+        .iter()
+        .map(|day| format!("extern crate {};\n", day.name))
+        .collect();
+    fs::write(out.join("days.rs"), externs).expect("OUT_DIR is writable");
+
+    let mut tests = String::new();
+    for day in &days {
+        let answers = day.path.join("answers.toml");
+        println!("cargo::rerun-if-changed={}", day.path.display());
+
+        let Some(slow) = is_slow(&answers) else {
+            continue;
+        };
+        if slow {
+            tests.push_str("#[ignore = \"slow on the real input\"]\n");
+        }
+        tests.push_str(&format!(
+            "#[test]\nfn {}() -> eyre::Result<()> {{\n    check({}, {})\n}}\n",
+            day.name, day.year, day.day
+        ));
+    }
+    fs::write(out.join("answer_tests.rs"), tests).expect("OUT_DIR is writable");
+}
@@ -0,0 +1,147 @@
+//! Allocation counting, compiled in with the `count-allocations` feature.
+//!
+//! Counts are kept per thread, so each part is measured on its solver thread alone; memory
+//! allocated by threads a solution spawns itself is not counted.
+#![cfg_attr(not(feature = "count-allocations"), allow(dead_code))]
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+};
+
+/// What one solve of a part allocated
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Stats {
+    /// Allocations, reallocations included
+    pub count: u64,
+    /// Bytes requested by all of them
+    pub bytes: u64,
+    /// Most bytes live at once
+    pub peak: u64,
+}
+
+thread_local! {
+    static COUNT: Cell<u64> = const { Cell::new(0) };
+    static BYTES: Cell<u64> = const { Cell::new(0) };
+    /// Signed, memory allocated before a measurement may be freed during it
+    static LIVE: Cell<i64> = const { Cell::new(0) };
+    static PEAK: Cell<i64> = const { Cell::new(0) };
+}
+
+/// Forwards to the system allocator, counting on the way
+pub struct Counting;
+
+impl Counting {
+    fn allocated(size: usize, freed: usize) {
+        // fails only while the thread's locals are being torn down
+        let _ = COUNT.try_with(|c| c.set(c.get() + 1));
+        let _ = BYTES.try_with(|b| b.set(b.get() + size as u64));
+        Self::resized(size as i64 - freed as i64);
+    }
+
+    fn resized(delta: i64) {
+        let _ = LIVE.try_with(|live| {
+            live.set(live.get() + delta);
+            let _ = PEAK.try_with(|peak| peak.set(peak.get().max(live.get())));
+        });
+    }
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc(layout) };
+        if !ptr.is_null() {
+            Self::allocated(layout.size(), 0);
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc_zeroed(layout) };
+        if !ptr.is_null() {
+            Self::allocated(layout.size(), 0);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) };
+        Self::resized(-(layout.size() as i64));
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new = unsafe { System.realloc(ptr, layout, new_size) };
+        if !new.is_null() {
+            Self::allocated(new_size, layout.size());
+        }
+        new
+    }
+}
+
+#[cfg(feature = "count-allocations")]
+#[global_allocator]
+static ALLOCATOR: Counting = Counting;
+
+/// Runs `f` and reports what it allocated on this thread, `None` unless the counting
+/// allocator is compiled in
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<Stats>) {
+    if !cfg!(feature = "count-allocations") {
+        return (f(), None);
+    }
+
+    COUNT.set(0);
+    BYTES.set(0);
+    LIVE.set(0);
+    PEAK.set(0);
+    let result = f();
+    let stats = Stats {
+        count: COUNT.get(),
+        bytes: BYTES.get(),
+        peak: PEAK.get().max(0) as u64,
+    };
+    (result, Some(stats))
+}
+
+/// `1.5 MiB`, exact below a KiB
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit + 1 < UNITS.len() {
+        value /= 1024.0;
+        unit += 1;
+    }
+    format!("{value:.1} {}", UNITS[unit])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::format_bytes;
+
+    #[test]
+    fn bytes() {
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(3 * 1024 * 1024), "3.0 MiB");
+    }
+
+    #[cfg(feature = "count-allocations")]
+    #[test]
+    fn counts_allocations() {
+        let (v, stats) = super::measure(|| {
+            let mut v = Vec::<u64>::with_capacity(16);
+            v.extend(0..16);
+            drop(Vec::<u8>::with_capacity(1000));
+            v
+        });
+        let stats = stats.unwrap();
+        assert_eq!(v.len(), 16);
+        assert_eq!(stats.count, 2);
+        assert_eq!(stats.bytes, 128 + 1000);
+        assert_eq!(stats.peak, 128 + 1000);
+    }
+}
@@ -0,0 +1,204 @@
+use std::{collections::BTreeMap, time::Duration};
+
+use aoc_util::{
+    Part,
+    table::{Align, Table},
+};
+use crate::run::Record;
+
+/// Changes smaller than this are noise whatever the percentage, the fastest parts take
+/// nanoseconds
+pub const NOISE: Duration = Duration::from_micros(5);
+
+/// Median time of every part from an earlier run, stored as TOML
+#[derive(Clone, Debug, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct Baseline {
+    /// Nanoseconds, keyed by `<year>/<day>/<part>`, or `<year>/<day>/parse` for parsing
+    #[serde(default)]
+    pub medians: BTreeMap<String, u64>,
+}
+
+/// A part, or parsing when `None`
+fn key(year: u16, day: u8, part: Option<Part>) -> String {
+    match part {
+        Some(part) => format!("{year}/{day:02}/{part}"),
+        None => format!("{year}/{day:02}/parse"),
+    }
+}
+
+impl Baseline {
+    /// The median of a part, or of parsing when `part` is `None`
+    pub fn get(&self, year: u16, day: u8, part: Option<Part>) -> Option<Duration> {
+        self.medians
+            .get(&key(year, day, part))
+            .copied()
+            .map(Duration::from_nanos)
+    }
+
+    /// Takes the medians of every solved record and of parsing, keeping the other parts as
+    /// they were
+    pub fn update(&mut self, records: &[Record]) {
+        let nanos = |d: Duration| d.as_nanos().try_into().unwrap_or(u64::MAX);
+        for r in records {
+            let (year, day) = (r.puzzle.year, r.puzzle.day);
+            if let Some(parse) = r.parse_median() {
+                self.medians.insert(key(year, day, None), nanos(parse));
+            }
+            if let Some(median) = r.median().filter(|_| r.outcome.answer().is_some()) {
+                self.medians
+                    .insert(key(year, day, Some(r.part)), nanos(median));
+            }
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Change {
+    /// Not in the baseline
+    New,
+    Unchanged,
+    Improved,
+    Regressed,
+    /// Did not solve, so there is nothing to compare
+    Failed,
+}
+
+/// Whether `median` moved past `threshold` percent of `baseline`, and past [`NOISE`]
+pub fn compare(baseline: Option<Duration>, median: Option<Duration>, threshold: f64) -> Change {
+    let (Some(baseline), Some(median)) = (baseline, median) else {
+        return match median {
+            Some(_) => Change::New,
+            None => Change::Failed,
+        };
+    };
+
+    let limit = baseline.as_secs_f64() * threshold / 100.0;
+    let diff = median.as_secs_f64() - baseline.as_secs_f64();
+    if diff.abs() <= limit || median.abs_diff(baseline) <= NOISE {
+        Change::Unchanged
+    } else if diff > 0.0 {
+        Change::Regressed
+    } else {
+        Change::Improved
+    }
+}
+
+fn percent(baseline: Duration, median: Duration) -> String {
+    let ratio = median.as_secs_f64() / baseline.as_secs_f64();
+    format!("{:+.1}%", (ratio - 1.0) * 100.0)
+}
+
+/// Every record next to its baseline, parsing on a row of its own before the parts of each
+/// day, and the number of regressions: parts that got slower, or failed with a baseline
+pub fn table(records: &[Record], baseline: &Baseline, threshold: f64) -> (Table, usize) {
+    let mut table = Table::new(&[
+        ("year", Align::Left),
+        ("day", Align::Right),
+        ("part", Align::Right),
+        ("title", Align::Left),
+        ("baseline", Align::Right),
+        ("median", Align::Right),
+        ("change", Align::Right),
+        ("", Align::Left),
+    ]);
+    let mut regressions = 0;
+    let format = |d: Option<Duration>| d.map(|d| format!("{d:.2?}")).unwrap_or_default();
+
+    let mut row = |r: &Record, part: Option<Part>, after: Option<Duration>| {
+        let before = baseline.get(r.puzzle.year, r.puzzle.day, part);
+        let flag = match compare(before, after, threshold) {
+            Change::New => "new".to_string(),
+            Change::Unchanged => String::new(),
+            Change::Improved => "improved".into(),
+            Change::Regressed => {
+                regressions += 1;
+                "REGRESSED".into()
+            }
+            Change::Failed => {
+                // no longer solving a part that used to be solved is the worst regression
+                if before.is_some() {
+                    regressions += 1;
+                }
+                r.outcome.to_string()
+            }
+        };
+        table.row(vec![
+            r.puzzle.year.to_string(),
+            format!("{:02}", r.puzzle.day),
+            part.map_or("parse".into(), |part| part.to_string()),
+            r.puzzle.title.to_string(),
+            format(before),
+            format(after),
+            before
+                .zip(after)
+                .map(|(before, after)| percent(before, after))
+                .unwrap_or_default(),
+            flag,
+        ]);
+    };
+
+    for day in
+        records.chunk_by(|a, b| (a.puzzle.year, a.puzzle.day) == (b.puzzle.year, b.puzzle.day))
+    {
+        if let Some(parse) = day[0].parse_median() {
+            row(&day[0], None, Some(parse));
+        }
+        for r in day {
+            row(
+                r,
+                Some(r.part),
+                r.median().filter(|_| r.outcome.answer().is_some()),
+            );
+        }
+    }
+
+    (table, regressions)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{Baseline, Change, compare, table};
+    use crate::run::{Outcome, Record, linked};
+    use aoc_util::Part;
+
+    #[test]
+    fn changes() {
+        let ms = |n| Some(Duration::from_millis(n));
+        let us = |n| Some(Duration::from_micros(n));
+
+        assert_eq!(compare(ms(100), ms(109), 10.0), Change::Unchanged);
+        assert_eq!(compare(ms(100), ms(111), 10.0), Change::Regressed);
+        assert_eq!(compare(ms(100), ms(80), 10.0), Change::Improved);
+        assert_eq!(compare(us(1), us(4), 10.0), Change::Unchanged);
+        assert_eq!(compare(None, ms(1), 10.0), Change::New);
+        assert_eq!(compare(ms(1), None, 10.0), Change::Failed);
+    }
+
+    #[test]
+    fn round_trip() -> eyre::Result<()> {
+        let mut baseline = Baseline::default();
+        baseline.medians.insert("2015/07/2".into(), 1500);
+
+        let s = toml::to_string(&baseline)?;
+        assert_eq!(s, "[medians]\n\"2015/07/2\" = 1500\n");
+        let baseline: Baseline = toml::from_str(&s)?;
+        assert_eq!(
+            baseline.get(2015, 7, Some(Part::Two)),
+            Some(Duration::from_nanos(1500))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn failing_with_a_baseline_is_a_regression() {
+        let puzzle = linked(2015, 1);
+        let mut baseline = Baseline::default();
+        baseline.medians.insert("2015/01/1".into(), 1500);
+
+        let record = |part| Record::new(puzzle, part, Outcome::TimedOut);
+        let (_, regressions) = table(&[record(Part::One), record(Part::Two)], &baseline, 10.0);
+        assert_eq!(regressions, 1);
+    }
+}
@@ -0,0 +1,202 @@
+use std::{
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use aoc_util::{
+    answers::Answers,
+    solution::Puzzle,
+    store,
+    submit::Attempts,
+    table::{Align, Table},
+};
+use eyre::WrapErr;
+use rayon::prelude::*;
+
+use crate::{
+    run::{self, Options, Record},
+    verify::Status,
+};
+
+/// The results of one input file of a batch
+#[derive(Debug)]
+pub struct Entry {
+    pub path: PathBuf,
+    pub records: Vec<Record>,
+    /// What the `.answers` sidecar of the file says of each record, `None` without a sidecar
+    pub statuses: Option<eyre::Result<Vec<Status>>>,
+}
+
+impl Entry {
+    pub fn is_ok(&self) -> bool {
+        let solved = self.records.iter().all(|r| r.outcome.answer().is_some());
+        solved
+            && match &self.statuses {
+                Some(Ok(statuses)) => statuses.iter().all(Status::is_ok),
+                Some(Err(_)) => false,
+                None => true,
+            }
+    }
+
+    fn time(&self) -> Duration {
+        let parse = self.records.first().and_then(Record::parse_median);
+        self.records
+            .iter()
+            .filter_map(Record::median)
+            .chain(parse)
+            .sum()
+    }
+}
+
+/// Whether `path` is one of the files [`Answers::sidecar`] or [`Attempts::sidecar`] keep
+/// beside an input, rather than an input itself
+fn is_sidecar(path: &Path) -> bool {
+    let inputs = [path.with_file_name("input.txt"), path.with_extension("txt")];
+    inputs.iter().filter(|input| *input != path).any(|input| {
+        Answers::sidecar(input) == path || Attempts::sidecar(input) == path
+    })
+}
+
+/// Every input in `dir`, by name, leaving out hidden files and the sidecars of answers and
+/// attempts
+pub fn inputs(dir: &Path) -> eyre::Result<Vec<PathBuf>> {
+    let mut paths = vec![];
+    for entry in
+        std::fs::read_dir(dir).wrap_err_with(|| format!("failed to read {}", dir.display()))?
+    {
+        let path = entry?.path();
+        let hidden = path
+            .file_name()
+            .is_some_and(|n| n.to_string_lossy().starts_with('.'));
+        if path.is_file() && !hidden && !is_sidecar(&path) {
+            paths.push(path);
+        }
+    }
+    paths.sort();
+    Ok(paths)
+}
+
+fn check(path: &Path, records: &[Record]) -> Option<eyre::Result<Vec<Status>>> {
+    let sidecar = Answers::sidecar(path);
+    if !sidecar.exists() {
+        return None;
+    }
+    Some(store::load_or_default::<Answers>(&sidecar).map(|answers| {
+        records
+            .iter()
+            .map(|r| match r.outcome.answer() {
+                Some(answer) => Status::Checked(answers.check(r.part, answer)),
+                None => Status::Failed,
+            })
+            .collect()
+    }))
+}
+
+/// Solves every input in `dir` with one puzzle on a thread pool, entries come back by file name
+pub fn batch(puzzle: &'static Puzzle, dir: &Path, options: &Options) -> eyre::Result<Vec<Entry>> {
+    let paths = inputs(dir)?;
+    if paths.is_empty() {
+        eyre::bail!("no inputs in {}", dir.display());
+    }
+
+    Ok(run::pool(&[puzzle], options)?.install(|| {
+        paths
+            .into_par_iter()
+            .map(|path| {
+                let records = match std::fs::read_to_string(&path) {
+                    Ok(input) => run::solve(puzzle, input.into(), options),
+                    Err(e) => {
+                        let e = eyre::Report::new(e)
+                            .wrap_err(format!("failed to read {}", path.display()));
+                        run::unsolved(puzzle, options, &e)
+                    }
+                };
+                let statuses = check(&path, &records);
+                Entry {
+                    path,
+                    records,
+                    statuses,
+                }
+            })
+            .collect()
+    }))
+}
+
+pub fn table(entries: &[Entry]) -> Table {
+    let mut table = Table::new(&[
+        ("file", Align::Left),
+        ("part 1", Align::Left),
+        ("part 2", Align::Left),
+        ("time", Align::Right),
+        ("check", Align::Left),
+    ]);
+
+    for entry in entries {
+        let name = entry.path.file_name().unwrap_or(entry.path.as_os_str());
+        let mut cells = vec![name.to_string_lossy().into_owned()];
+        for part in aoc_util::Part::ALL {
+            let record = entry.records.iter().find(|r| r.part == part);
+            cells.push(record.map(|r| r.outcome.to_string()).unwrap_or_default());
+        }
+        cells.push(format!("{:.2?}", entry.time()));
+        cells.push(match &entry.statuses {
+            Some(Ok(statuses)) => entry
+                .records
+                .iter()
+                .zip(statuses)
+                .map(|(r, status)| format!("{}: {status}", r.part))
+                .collect::<Vec<_>>()
+                .join(", "),
+            Some(Err(e)) => format!("ERROR: {e:#}"),
+            None => String::new(),
+        });
+        table.row(cells);
+    }
+
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{batch, inputs, table};
+    use crate::run::{Options, linked};
+
+    #[test]
+    fn solves_and_checks_every_input() -> eyre::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let dir = dir.path();
+        std::fs::write(dir.join("a.txt"), "()())")?;
+        std::fs::write(dir.join("a.answers"), "part1 = -1\npart2 = 5\n")?;
+        std::fs::write(dir.join("b.txt"), "))(")?;
+        std::fs::write(dir.join("b.answers"), "part1 = 1\n")?;
+        std::fs::write(dir.join("c"), "not parentheses")?;
+        std::fs::write(dir.join(".hidden"), "")?;
+        std::fs::write(dir.join("b.attempts"), "")?;
+        std::fs::write(dir.join("answers.toml"), "")?;
+        std::fs::write(dir.join("attempts.toml"), "")?;
+
+        let names: Vec<_> = inputs(dir)?
+            .iter()
+            .map(|p| p.file_name().unwrap().to_owned())
+            .collect();
+        assert_eq!(names, ["a.txt", "b.txt", "c"]);
+
+        let puzzle = linked(2015, 1);
+        let options = Options {
+            jobs: Some(2),
+            ..Default::default()
+        };
+        let entries = batch(puzzle, dir, &options)?;
+        let ok: Vec<_> = entries.iter().map(|e| e.is_ok()).collect();
+        assert_eq!(ok, [true, false, false]);
+
+        let table = table(&entries).to_string();
+        assert!(table.contains("1: ok, 2: ok"), "{table}");
+        assert!(
+            table.contains("1: WRONG, expected 1, 2: unknown"),
+            "{table}"
+        );
+        assert!(table.contains("ERROR: "), "{table}");
+        Ok(())
+    }
+}
@@ -0,0 +1,543 @@
+use std::{
+    path::{Path, PathBuf},
+    process,
+    time::Duration,
+};
+
+use aoc_util::{
+    Part,
+    client::Client,
+    input::{self, Locator},
+    profile::Config,
+    solution::{self, Param, Puzzle},
+    store,
+};
+use clap::Parser;
+use eyre::WrapErr;
+
+include!(concat!(env!("OUT_DIR"), "/days.rs"));
+
+mod alloc;
+mod baseline;
+mod batch;
+mod output;
+mod readme;
+mod run;
+mod scaffold;
+mod serve;
+mod status;
+mod verify;
+
+#[derive(clap::Parser, Debug)]
+#[command(about = "Runs the Advent of Code solutions in this workspace")]
+struct Args {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(clap::Subcommand, Debug)]
+enum Command {
+    /// Solve a day, a whole year, or every day, and print a table of answers and timings
+    Run {
+        year: Option<u16>,
+        day: Option<u8>,
+
+        /// Only solve this part
+        #[arg(short, long)]
+        part: Option<Part>,
+
+        #[command(flatten)]
+        input: InputArgs,
+
+        /// Solve each part this many times and report the median and minimum
+        #[arg(short, long, default_value_t = 1)]
+        repeat: usize,
+
+        /// Worker threads, one per core by default; use 1 for the most reliable timings
+        #[arg(short, long)]
+        jobs: Option<usize>,
+
+        /// Give up on a part after this many seconds
+        #[arg(short, long, value_parser = parse_seconds)]
+        timeout: Option<Duration>,
+
+        /// Override a parameter of the puzzles that declare it, e.g. `--param steps=4`
+        #[arg(long = "param", value_name = "NAME=VALUE")]
+        params: Vec<Param>,
+
+        #[arg(short, long, value_enum, default_value_t)]
+        format: output::Format,
+    },
+    /// Check answers against each day's known answers, and optionally record new ones
+    Verify {
+        year: Option<u16>,
+        day: Option<u8>,
+
+        #[command(flatten)]
+        input: InputArgs,
+
+        /// Worker threads, one per core by default
+        #[arg(short, long)]
+        jobs: Option<usize>,
+
+        /// Give up on a part after this many seconds
+        #[arg(short, long, value_parser = parse_seconds)]
+        timeout: Option<Duration>,
+
+        /// Save the answers of parts that have no known answer yet
+        #[arg(long)]
+        record: bool,
+    },
+    /// Solve every input file in a directory with one day, checking each against its
+    /// `<name>.answers` file when there is one
+    Batch {
+        year: u16,
+        day: u8,
+        dir: PathBuf,
+
+        /// Worker threads, one per core by default
+        #[arg(short, long)]
+        jobs: Option<usize>,
+
+        /// Give up on a part after this many seconds
+        #[arg(short, long, value_parser = parse_seconds)]
+        timeout: Option<Duration>,
+
+        /// Override a parameter of the day, e.g. `--param steps=4`
+        #[arg(long = "param", value_name = "NAME=VALUE")]
+        params: Vec<Param>,
+    },
+    /// Create the crate for a new day and add it to the runner
+    New {
+        year: u16,
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+
+        #[arg(short, long, value_enum, default_value_t)]
+        flavor: scaffold::Flavor,
+
+        /// Puzzle title
+        #[arg(long, default_value = "")]
+        title: String,
+
+        /// Also fetch the input of every configured profile, or else the one of the session in
+        /// AOC_SESSION as the day's input.txt
+        #[arg(long)]
+        fetch: bool,
+    },
+    /// Run the unit tests of a day, a whole year, or every day
+    Test {
+        year: Option<u16>,
+        day: Option<u8>,
+
+        /// Passed through to the test harness
+        #[arg(last = true)]
+        extra: Vec<String>,
+    },
+    /// Run the benches of a day, a whole year, or every day
+    Bench {
+        year: Option<u16>,
+        day: Option<u8>,
+
+        /// Passed through to divan
+        #[arg(last = true)]
+        extra: Vec<String>,
+    },
+    /// Time every part and compare the medians to a saved baseline, failing on regressions
+    Perf {
+        year: Option<u16>,
+        day: Option<u8>,
+
+        /// Solve each part this many times and compare the median
+        #[arg(short, long, default_value_t = 10)]
+        repeat: usize,
+
+        /// Worker threads; more finish sooner but time less reliably
+        #[arg(short, long, default_value_t = 1)]
+        jobs: usize,
+
+        /// Give up on a part after this many seconds
+        #[arg(short, long, value_parser = parse_seconds)]
+        timeout: Option<Duration>,
+
+        /// Percentage a median may grow by before it counts as a regression
+        #[arg(long, default_value_t = 10.0)]
+        threshold: f64,
+
+        /// Baseline file, `target/aoc/baseline.toml` in the workspace by default
+        #[arg(long)]
+        baseline: Option<PathBuf>,
+
+        /// Store the medians of this run as the new baseline
+        #[arg(long)]
+        save: bool,
+    },
+    /// List the registered solutions
+    List { year: Option<u16> },
+    /// Regenerate the progress table in the README from a fresh run of every day
+    Readme {
+        /// Solve each part this many times and show the median
+        #[arg(short, long, default_value_t = 5)]
+        repeat: usize,
+
+        /// Worker threads, one per core by default; use 1 for the most reliable timings
+        #[arg(short, long)]
+        jobs: Option<usize>,
+
+        /// Give up on a part after this many seconds
+        #[arg(short, long, value_parser = parse_seconds)]
+        timeout: Option<Duration>,
+
+        /// README to update, the workspace's by default
+        #[arg(long)]
+        path: Option<PathBuf>,
+    },
+    /// Serve the registered solutions over HTTP on localhost: `GET /days` lists them and
+    /// `POST /solve/<year>/<day>` solves the input in the body, returning JSON answers and timings
+    Serve {
+        #[arg(short, long, default_value_t = 8025)]
+        port: u16,
+
+        /// Worker threads, one per core by default
+        #[arg(short, long)]
+        jobs: Option<usize>,
+
+        /// Give up on a part after this many seconds, so one slow input cannot hold up a worker
+        #[arg(short, long, value_parser = parse_seconds, default_value = "30")]
+        timeout: Duration,
+    },
+    /// Draw the calendar of each year: which parts are answered, implemented, stubbed or missing
+    Status {
+        year: Option<u16>,
+
+        #[command(flatten)]
+        input: InputArgs,
+    },
+}
+
+#[derive(clap::Args, Debug)]
+struct InputArgs {
+    /// Read the input from this file, or stdin for `-`; only valid for a single day
+    #[arg(short, long)]
+    input: Option<PathBuf>,
+
+    /// Directory of inputs laid out as `<year>/<day>.txt`, tried before each day's input.txt
+    #[arg(long, env = input::INPUTS_ENV)]
+    inputs: Option<PathBuf>,
+
+    /// Use the inputs and answers of this profile from the config file
+    #[arg(short = 'P', long, conflicts_with = "input")]
+    profile: Option<String>,
+
+    /// Solve once for every profile in the config file
+    #[arg(long, conflicts_with_all = ["input", "profile"])]
+    every_profile: bool,
+}
+
+impl InputArgs {
+    /// One locator per profile to solve for, a single unnamed one without profiles
+    fn locators(self, puzzles: &[&Puzzle]) -> eyre::Result<Vec<(Option<String>, Locator)>> {
+        if self.input.is_some() && puzzles.len() > 1 {
+            eyre::bail!("--input can only be used when solving a single day");
+        }
+
+        if self.every_profile {
+            let config = Config::from_env()?;
+            if config.profiles.is_empty() {
+                eyre::bail!("no profiles configured");
+            }
+            return Ok(config
+                .profiles
+                .values()
+                .map(|profile| (Some(profile.name.clone()), profile.locator()))
+                .collect());
+        }
+        if let Some(name) = self.profile {
+            let locator = Config::from_env()?.profile(&name)?.locator();
+            return Ok(vec![(Some(name), locator)]);
+        }
+
+        Ok(vec![(
+            None,
+            Locator {
+                path: self.input,
+                inputs_dir: self.inputs,
+                inputs_only: false,
+            },
+        )])
+    }
+}
+
+/// Heads the results of each profile when solving for several
+fn print_profile(profile: Option<&str>) {
+    if let Some(profile) = profile {
+        println!("\nprofile {profile}");
+    }
+}
+
+fn workspace() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("the runner lives inside the workspace")
+}
+
+fn parse_seconds(s: &str) -> eyre::Result<Duration> {
+    Ok(Duration::try_from_secs_f64(s.parse()?)?)
+}
+
+fn select(year: Option<u16>, day: Option<u8>) -> eyre::Result<Vec<&'static Puzzle>> {
+    let puzzles: Vec<_> = solution::all()
+        .into_iter()
+        .filter(|p| year.is_none_or(|y| p.year == y) && day.is_none_or(|d| p.day == d))
+        .collect();
+
+    if puzzles.is_empty() {
+        match (year, day) {
+            (Some(year), Some(day)) => eyre::bail!("no solution registered for {year}/{day:02}"),
+            (Some(year), None) => eyre::bail!("no solutions registered for {year}"),
+            _ => eyre::bail!("no solutions registered"),
+        }
+    }
+    Ok(puzzles)
+}
+
+/// Runs a cargo subcommand over the packages of the given puzzles
+fn cargo(subcommand: &str, puzzles: &[&Puzzle], extra: &[String]) -> eyre::Result<()> {
+    let cargo = std::env::var_os("CARGO").unwrap_or_else(|| "cargo".into());
+
+    let mut command = process::Command::new(cargo);
+    command.arg(subcommand);
+    for puzzle in puzzles {
+        command.args(["-p", puzzle.package]);
+    }
+    if !extra.is_empty() {
+        command.arg("--").args(extra);
+    }
+
+    let status = command.status().wrap_err("failed to start cargo")?;
+    if !status.success() {
+        eyre::bail!("cargo {subcommand} failed: {status}");
+    }
+    Ok(())
+}
+
+fn main() -> eyre::Result<()> {
+    let args = Args::parse();
+
+    match args.command {
+        Command::Run {
+            year,
+            day,
+            part,
+            input,
+            repeat,
+            jobs,
+            timeout,
+            params,
+            format,
+        } => {
+            let puzzles = select(year, day)?;
+            let options = run::Options {
+                part,
+                repeat,
+                jobs,
+                timeout,
+                params,
+            };
+
+            let mut sections = vec![];
+            for (profile, locator) in input.locators(&puzzles)? {
+                sections.push((profile, run::run(&puzzles, &locator, &options)?));
+            }
+            match format {
+                output::Format::Table => {
+                    for (profile, records) in &sections {
+                        print_profile(profile.as_deref());
+                        print!("{}", run::table(records, repeat));
+                    }
+                }
+                format => output::write(&mut std::io::stdout().lock(), format, &sections)?,
+            }
+            Ok(())
+        }
+        Command::Verify {
+            year,
+            day,
+            input,
+            jobs,
+            timeout,
+            record,
+        } => {
+            let puzzles = select(year, day)?;
+            let options = run::Options {
+                jobs,
+                timeout,
+                ..Default::default()
+            };
+
+            let mut failed = 0;
+            for (profile, locator) in input.locators(&puzzles)? {
+                let records = run::run(&puzzles, &locator, &options)?;
+                let statuses = verify::verify(&records, &locator, record)?;
+                print_profile(profile.as_deref());
+                print!("{}", verify::table(&records, &statuses));
+
+                failed += statuses.iter().filter(|s| !s.is_ok()).count();
+            }
+            if failed > 0 {
+                eyre::bail!("{failed} parts failed or did not match their known answer");
+            }
+            Ok(())
+        }
+        Command::Batch {
+            year,
+            day,
+            dir,
+            jobs,
+            timeout,
+            params,
+        } => {
+            let puzzle = select(Some(year), Some(day))?[0];
+            let options = run::Options {
+                jobs,
+                timeout,
+                params,
+                ..Default::default()
+            };
+
+            let entries = batch::batch(puzzle, &dir, &options)?;
+            print!("{}", batch::table(&entries));
+
+            let failed = entries.iter().filter(|e| !e.is_ok()).count();
+            if failed > 0 {
+                eyre::bail!("{failed} inputs failed or did not match their known answers");
+            }
+            Ok(())
+        }
+        Command::New {
+            year,
+            day,
+            flavor,
+            title,
+            fetch,
+        } => {
+            let new = scaffold::NewDay {
+                year,
+                day,
+                title,
+                flavor,
+            };
+            let profiles = Config::from_env()?.profiles;
+            let input = match fetch && profiles.is_empty() {
+                true => Some(Client::from_env()?.input(year, day)?),
+                false => None,
+            };
+
+            let dir = scaffold::create(workspace(), &new, input.as_deref())?;
+            println!("created {} in {}", new.package(), dir.display());
+
+            for profile in profiles.values().filter(|_| fetch) {
+                let path = profile.input_path(year, day);
+                std::fs::create_dir_all(path.parent().expect("inputs are in a year directory"))?;
+                std::fs::write(&path, profile.client().input(year, day)?)?;
+                println!("saved the input of {} in {}", profile.name, path.display());
+            }
+            Ok(())
+        }
+        Command::Test { year, day, extra } => cargo("test", &select(year, day)?, &extra),
+        Command::Bench { year, day, extra } => cargo("bench", &select(year, day)?, &extra),
+        Command::Perf {
+            year,
+            day,
+            repeat,
+            jobs,
+            timeout,
+            threshold,
+            baseline: path,
+            save,
+        } => {
+            let path = path.unwrap_or_else(|| workspace().join("target/aoc/baseline.toml"));
+            let mut baseline: baseline::Baseline = store::load_or_default(&path)?;
+
+            let options = run::Options {
+                repeat,
+                jobs: Some(jobs),
+                timeout,
+                ..Default::default()
+            };
+            let records = run::run(&select(year, day)?, &Locator::from_env(), &options)?;
+            let (table, regressions) = baseline::table(&records, &baseline, threshold);
+            print!("{table}");
+
+            if save {
+                baseline.update(&records);
+                store::save(&path, &baseline)?;
+                println!("saved the baseline in {}", path.display());
+            } else if regressions > 0 {
+                eyre::bail!(
+                    "{regressions} parts failed or are more than {threshold}% slower than the baseline"
+                );
+            }
+            Ok(())
+        }
+        Command::List { year } => {
+            for puzzle in select(year, None)? {
+                println!("{puzzle}");
+            }
+            Ok(())
+        }
+        Command::Readme {
+            repeat,
+            jobs,
+            timeout,
+            path,
+        } => {
+            let path = path.unwrap_or_else(|| workspace().join("README.md"));
+            let readme = std::fs::read_to_string(&path)
+                .wrap_err_with(|| format!("failed to read {}", path.display()))?;
+
+            let options = run::Options {
+                repeat,
+                jobs,
+                timeout,
+                ..Default::default()
+            };
+            let locator = Locator::from_env();
+            let records = run::run(&select(None, None)?, &locator, &options)?;
+            let root = path
+                .parent()
+                .filter(|dir| !dir.as_os_str().is_empty())
+                .unwrap_or(Path::new("."));
+            let generated = readme::markdown(&records, &locator, &root.canonicalize()?)?;
+
+            std::fs::write(&path, readme::splice(&readme, &generated)?)
+                .wrap_err_with(|| format!("failed to write {}", path.display()))?;
+            println!("updated {}", path.display());
+            Ok(())
+        }
+        Command::Serve {
+            port,
+            jobs,
+            timeout,
+        } => {
+            let options = run::Options {
+                jobs,
+                timeout: Some(timeout),
+                ..Default::default()
+            };
+            let server = serve::bind(&format!("127.0.0.1:{port}"), options)?;
+            println!("listening on {}", server.url());
+            server.wait();
+            Ok(())
+        }
+        Command::Status { year, input } => {
+            let puzzles = solution::all();
+            for (profile, locator) in input.locators(&puzzles)? {
+                print_profile(profile.as_deref());
+                print!("{}", status::table(&status::calendars(&puzzles, year, &locator)?));
+            }
+            println!("\n{}", status::legend());
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,154 @@
+use std::{io::Write, process};
+
+use crate::run::Record;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum Format {
+    /// Human readable table with totals
+    #[default]
+    Table,
+    /// One JSON object per part and line
+    Json,
+    Csv,
+}
+
+/// Flat, machine readable form of a [`Record`]
+#[derive(Debug, serde::Serialize)]
+pub struct Row<'a> {
+    pub revision: &'a str,
+    /// Whose input was solved, when solving for profiles
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub profile: Option<&'a str>,
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub title: &'a str,
+    pub success: bool,
+    pub answer: Option<&'a str>,
+    /// Error, panic or timeout, as shown in the table
+    pub error: Option<String>,
+    /// Median wall-clock time
+    pub duration_ns: Option<u64>,
+    pub min_ns: Option<u64>,
+    pub runs: usize,
+    /// Median time to parse the input, which both parts of the day share
+    pub parse_ns: Option<u64>,
+    /// Only with the `count-allocations` feature
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allocations: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allocated_bytes: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub peak_bytes: Option<u64>,
+}
+
+const CSV_HEADER: &str =
+    "revision,year,day,part,title,success,answer,error,duration_ns,min_ns,runs,profile,parse_ns,allocations,allocated_bytes,peak_bytes";
+
+impl<'a> Row<'a> {
+    pub fn new(record: &'a Record, revision: &'a str, profile: Option<&'a str>) -> Self {
+        let answer = record.outcome.answer();
+        let error = answer.is_none().then(|| record.outcome.to_string());
+        let nanos = |d: std::time::Duration| d.as_nanos() as u64;
+
+        Self {
+            revision,
+            profile,
+            year: record.puzzle.year,
+            day: record.puzzle.day,
+            part: record.part.number(),
+            title: record.puzzle.title,
+            success: error.is_none(),
+            answer,
+            error,
+            duration_ns: record.median().map(nanos),
+            min_ns: record.min().map(nanos),
+            runs: record.samples.len(),
+            parse_ns: record.parse_median().map(nanos),
+            allocations: record.allocations.map(|s| s.count),
+            allocated_bytes: record.allocations.map(|s| s.bytes),
+            peak_bytes: record.allocations.map(|s| s.peak),
+        }
+    }
+
+    fn csv(&self) -> String {
+        let optional = |n: Option<u64>| n.map(|n| n.to_string()).unwrap_or_default();
+        [
+            csv_field(self.revision),
+            self.year.to_string(),
+            self.day.to_string(),
+            self.part.to_string(),
+            csv_field(self.title),
+            self.success.to_string(),
+            csv_field(self.answer.unwrap_or_default()),
+            csv_field(self.error.as_deref().unwrap_or_default()),
+            optional(self.duration_ns),
+            optional(self.min_ns),
+            self.runs.to_string(),
+            csv_field(self.profile.unwrap_or_default()),
+            optional(self.parse_ns),
+            optional(self.allocations),
+            optional(self.allocated_bytes),
+            optional(self.peak_bytes),
+        ]
+        .join(",")
+    }
+}
+
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+/// The commit the records were produced from, as `git describe` sees the working tree
+pub fn revision() -> String {
+    process::Command::new("git")
+        .args(["describe", "--always", "--dirty"])
+        .output()
+        .ok()
+        .filter(|out| out.status.success())
+        .and_then(|out| String::from_utf8(out.stdout).ok())
+        .map(|s| s.trim().to_string())
+        .unwrap_or_else(|| "unknown".into())
+}
+
+/// Writes the records of every profile as JSON lines or CSV, the table format is rendered
+/// by the caller
+pub fn write(
+    out: &mut impl Write,
+    format: Format,
+    sections: &[(Option<String>, Vec<Record>)],
+) -> eyre::Result<()> {
+    let revision = revision();
+
+    if format == Format::Csv {
+        writeln!(out, "{CSV_HEADER}")?;
+    }
+    let records = sections
+        .iter()
+        .flat_map(|(profile, records)| records.iter().map(move |r| (profile.as_deref(), r)));
+    for (profile, record) in records {
+        let row = Row::new(record, &revision, profile);
+        match format {
+            Format::Json => writeln!(out, "{}", serde_json::to_string(&row)?)?,
+            Format::Csv => writeln!(out, "{}", row.csv())?,
+            Format::Table => unreachable!("tables are not written row by row"),
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::csv_field;
+
+    #[test]
+    fn csv_quoting() {
+        assert_eq!(csv_field("1234"), "1234");
+        assert_eq!(csv_field("Elves Look, Elves Say"), "\"Elves Look, Elves Say\"");
+        assert_eq!(csv_field("say \"hi\", ok"), "\"say \"\"hi\"\", ok\"");
+    }
+}
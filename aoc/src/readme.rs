@@ -0,0 +1,183 @@
+use std::{fmt::Write, path::Path};
+
+use aoc_util::{
+    Part,
+    answers::{Answers, Verdict},
+    input::Locator,
+};
+
+use crate::{
+    run::{Outcome, Record},
+    status::{self, State},
+};
+
+/// The generated table replaces everything between these two lines of the README
+pub const BEGIN: &str = "<!-- progress:begin -->";
+pub const END: &str = "<!-- progress:end -->";
+
+const VERIFIED: &str = "⭐";
+
+/// A known answer only verifies a part that still gives it on this run
+fn badge(part: Part, state: State, record: Option<&Record>, answers: &Answers) -> &'static str {
+    let verdict = record
+        .and_then(|r| r.outcome.answer())
+        .map(|answer| answers.check(part, answer));
+    match (state, verdict) {
+        (State::Stubbed, _) => "🚧",
+        (State::Missing, _) => "",
+        (_, Some(Verdict::Correct)) => VERIFIED,
+        (_, Some(Verdict::Unknown)) => "☆",
+        (_, Some(Verdict::Wrong { .. }) | None) => "❌",
+    }
+}
+
+/// Median time of a part, stubs are too quick to be worth showing
+fn time(record: Option<&Record>, state: State) -> String {
+    match record {
+        _ if state == State::Stubbed => String::new(),
+        Some(record) if record.outcome == Outcome::TimedOut => "timed out".into(),
+        Some(record) if record.outcome.answer().is_some() => record
+            .median()
+            .map(|d| format!("{d:.2?}"))
+            .unwrap_or_default(),
+        Some(_) => "failed".into(),
+        None => String::new(),
+    }
+}
+
+/// One section per year with a row per day: a link to its crate, its stars and the median
+/// time of parsing and of each part. `root` is the directory of the README, links are relative to it.
+pub fn markdown(records: &[Record], locator: &Locator, root: &Path) -> eyre::Result<String> {
+    let mut out = String::new();
+    let years = records.chunk_by(|a, b| a.puzzle.year == b.puzzle.year);
+
+    for (i, year) in years.enumerate() {
+        let days: Vec<_> = year
+            .chunk_by(|a, b| a.puzzle.day == b.puzzle.day)
+            .map(|day| {
+                let puzzle = day[0].puzzle;
+                let answers = status::answers(puzzle, locator)?;
+                let states = status::states(puzzle, locator)?;
+                let badges: [_; 2] = std::array::from_fn(|i| {
+                    let part = Part::ALL[i];
+                    let record = day.iter().find(|r| r.part == part);
+                    badge(part, states[i], record, &answers)
+                });
+                Ok((day, states, badges))
+            })
+            .collect::<eyre::Result<_>>()?;
+        let stars = days
+            .iter()
+            .flat_map(|(_, _, badges)| badges)
+            .filter(|&&b| b == VERIFIED)
+            .count();
+
+        if i > 0 {
+            writeln!(out)?;
+        }
+        writeln!(out, "### {} ({stars}/50 ⭐)\n", year[0].puzzle.year)?;
+        writeln!(out, "| Day | Title | Stars | Parse | Part 1 | Part 2 |")?;
+        writeln!(out, "| --: | :---- | :---: | ----: | -----: | -----: |")?;
+
+        for (records, states, badges) in days {
+            let puzzle = records[0].puzzle;
+            let dir = Path::new(puzzle.manifest_dir);
+            let link = dir.strip_prefix(root).unwrap_or(dir).display().to_string();
+            let part = |part| records.iter().find(|r| r.part == part);
+
+            writeln!(
+                out,
+                "| [{:02}]({}) | {} | {}{} | {} | {} | {} |",
+                puzzle.day,
+                link.replace('\\', "/"),
+                puzzle.title.replace('|', "\\|"),
+                badges[0],
+                badges[1],
+                records[0]
+                    .parse_median()
+                    .map(|d| format!("{d:.2?}"))
+                    .unwrap_or_default(),
+                time(part(Part::One), states[0]),
+                time(part(Part::Two), states[1]),
+            )?;
+        }
+    }
+
+    writeln!(
+        out,
+        "\n⭐ verified against a known answer, ☆ solved without one, ❌ failed or wrong on this \
+         run, 🚧 not solved yet"
+    )?;
+    Ok(out)
+}
+
+/// Replaces the generated part of `readme` with `generated`
+pub fn splice(readme: &str, generated: &str) -> eyre::Result<String> {
+    let (Some(begin), Some(end)) = (readme.find(BEGIN), readme.find(END)) else {
+        eyre::bail!("the README has no {BEGIN} and {END} lines to put the progress between");
+    };
+    if end < begin {
+        eyre::bail!("{END} comes before {BEGIN} in the README");
+    }
+
+    Ok(format!(
+        "{}{BEGIN}\n\n{generated}\n{}",
+        &readme[..begin],
+        &readme[end..]
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use aoc_util::{Part, input::Locator};
+
+    use super::{markdown, splice};
+    use crate::run::{Outcome, Record, linked};
+
+    #[test]
+    fn splices_between_the_markers() -> eyre::Result<()> {
+        let readme = "# AoC\n\n<!-- progress:begin -->\nold\n<!-- progress:end -->\n\nmore\n";
+        assert_eq!(
+            splice(readme, "new\n")?,
+            "# AoC\n\n<!-- progress:begin -->\n\nnew\n\n<!-- progress:end -->\n\nmore\n"
+        );
+        assert_eq!(
+            splice(&splice(readme, "new\n")?, "new\n")?,
+            splice(readme, "new\n")?
+        );
+        assert!(splice("# AoC\n", "new\n").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn only_answers_of_this_run_are_verified() -> eyre::Result<()> {
+        let puzzle = linked(2015, 1);
+        let dir = tempfile::tempdir()?;
+        let dir = dir.path();
+        let input = dir.join("01.txt");
+        std::fs::write(&input, "()())")?;
+        std::fs::write(dir.join("01.answers"), "part1 = -1\npart2 = 5\n")?;
+        let locator = Locator {
+            path: Some(input),
+            ..Default::default()
+        };
+
+        let record = |part, outcome| Record::new(puzzle, part, outcome);
+        let row = |one, two| -> eyre::Result<String> {
+            let records = [record(Part::One, one), record(Part::Two, two)];
+            let generated = markdown(&records, &locator, Path::new("/"))?;
+            let row = generated.lines().find(|l| l.starts_with("| [01]"));
+            Ok(row.expect("the day has a row").to_string())
+        };
+
+        let solved = || Outcome::Solved("-1".into());
+        assert!(row(solved(), Outcome::Solved("5".into()))?.contains("| ⭐⭐ |"));
+        assert!(row(solved(), Outcome::TimedOut)?.contains("| ⭐❌ |"));
+        assert!(row(solved(), Outcome::Solved("6".into()))?.contains("| ⭐❌ |"));
+        let failed = row(Outcome::Failed("oops".into()), Outcome::Solved("5".into()))?;
+        assert!(failed.contains("| ❌⭐ |"));
+        Ok(())
+    }
+}
@@ -0,0 +1,517 @@
+use std::{
+    any::Any,
+    fmt::Display,
+    panic,
+    sync::{
+        Arc, Once,
+        atomic::{AtomicUsize, Ordering},
+        mpsc,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use aoc_util::{
+    Part,
+    input::Locator,
+    solution::{Param, Puzzle},
+    table::{Align, Table},
+};
+use rayon::prelude::*;
+
+use crate::alloc::{self, Stats};
+
+/// Some solutions recurse deeply, the default 2MiB of a spawned thread is not enough for them
+const STACK_SIZE: usize = 256 * 1024 * 1024;
+
+/// Name of the threads parts are solved on, panics on them are reported in the results instead
+const SOLVER_THREAD: &str = "aoc-solver";
+
+static SOLVERS: AtomicUsize = AtomicUsize::new(0);
+
+/// Solver threads still running, a part that timed out keeps its thread until it returns
+pub fn solvers() -> usize {
+    SOLVERS.load(Ordering::Relaxed)
+}
+
+/// Counts a solver thread as running until dropped
+struct Running;
+
+impl Running {
+    fn start() -> Self {
+        SOLVERS.fetch_add(1, Ordering::Relaxed);
+        Self
+    }
+}
+
+impl Drop for Running {
+    fn drop(&mut self) {
+        SOLVERS.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Options {
+    /// Only solve this part
+    pub part: Option<Part>,
+    /// How many times each part is solved, to get a stable median
+    pub repeat: usize,
+    /// Worker threads, one per core when unset
+    pub jobs: Option<usize>,
+    /// Time budget for a single solve of a part
+    pub timeout: Option<Duration>,
+    /// Overrides of the puzzles' params, each only applied to the puzzles that declare it
+    pub params: Vec<Param>,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            part: None,
+            repeat: 1,
+            jobs: None,
+            timeout: None,
+            params: vec![],
+        }
+    }
+}
+
+impl Options {
+    fn parts(&self) -> impl Iterator<Item = Part> + use<> {
+        let only = self.part;
+        Part::ALL
+            .into_iter()
+            .filter(move |p| only.is_none_or(|only| only == *p))
+    }
+
+    fn params(&self, puzzle: &Puzzle) -> Vec<Param> {
+        self.params
+            .iter()
+            .filter(|param| puzzle.params.contains(&param.name.as_str()))
+            .cloned()
+            .collect()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Solved(String),
+    Failed(String),
+    Panicked(String),
+    /// The part did not finish within the time budget, it is left running in the background
+    TimedOut,
+}
+
+impl Outcome {
+    pub fn answer(&self) -> Option<&str> {
+        match self {
+            Outcome::Solved(answer) => Some(answer),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Outcome::Solved(answer) => write!(f, "{answer}"),
+            Outcome::Failed(error) => write!(f, "ERROR: {error}"),
+            Outcome::Panicked(message) => write!(f, "PANIC: {message}"),
+            Outcome::TimedOut => write!(f, "TIMEOUT"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Record {
+    pub puzzle: &'static Puzzle,
+    pub part: Part,
+    pub outcome: Outcome,
+    /// Wall-clock time of every repetition, empty if the part never ran
+    pub samples: Vec<Duration>,
+    /// Time of every repetition of parsing the input, shared by both parts of a puzzle
+    pub parse: Vec<Duration>,
+    /// Allocations of the last repetition, with the `count-allocations` feature
+    pub allocations: Option<Stats>,
+}
+
+impl Record {
+    /// A part that ended with `outcome` before any timing
+    pub fn new(puzzle: &'static Puzzle, part: Part, outcome: Outcome) -> Self {
+        Self {
+            puzzle,
+            part,
+            outcome,
+            samples: vec![],
+            parse: vec![],
+            allocations: None,
+        }
+    }
+
+    pub fn median(&self) -> Option<Duration> {
+        let mut samples = self.samples.clone();
+        samples.sort();
+        samples.get(samples.len() / 2).copied()
+    }
+
+    pub fn min(&self) -> Option<Duration> {
+        self.samples.iter().min().copied()
+    }
+
+    pub fn parse_median(&self) -> Option<Duration> {
+        let mut parse = self.parse.clone();
+        parse.sort();
+        parse.get(parse.len() / 2).copied()
+    }
+}
+
+/// Keeps the default panic output for every thread except the solvers
+fn quiet_solver_panics() {
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| {
+        let default = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if thread::current().name() != Some(SOLVER_THREAD) {
+                default(info);
+            }
+        }));
+    });
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    payload
+        .downcast_ref::<&str>()
+        .map(|s| s.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "panicked with a non-string payload".into())
+}
+
+/// Reported by the solver thread after every repetition of a step
+struct Sample {
+    /// `None` while parsing
+    part: Option<Part>,
+    outcome: Outcome,
+    elapsed: Duration,
+    allocations: Option<Stats>,
+}
+
+fn outcome<T>(result: thread::Result<eyre::Result<T>>) -> Result<T, Outcome> {
+    match result {
+        Ok(Ok(value)) => Ok(value),
+        Ok(Err(e)) => Err(Outcome::Failed(format!("{e:#}"))),
+        Err(payload) => Err(Outcome::Panicked(panic_message(payload.as_ref()))),
+    }
+}
+
+/// Parses the input, timing it when asked to, then solves `parts` one after the other on the
+/// shared parsed input
+fn spawn(
+    puzzle: &'static Puzzle,
+    input: Arc<str>,
+    params: Vec<Param>,
+    parts: Vec<Part>,
+    time_parse: bool,
+    repeat: usize,
+    tx: mpsc::Sender<Sample>,
+) -> std::io::Result<()> {
+    let running = Running::start();
+    let solver = move || {
+        let _running = running;
+        let mut parsed = None;
+        for _ in 0..if time_parse { repeat } else { 1 } {
+            let start = Instant::now();
+            let result = outcome(panic::catch_unwind(|| puzzle.parse(&input, &params)));
+            let elapsed = start.elapsed();
+
+            let outcome = match result {
+                Ok(input) => {
+                    parsed = Some(input);
+                    Outcome::Solved(String::new())
+                }
+                Err(outcome) => outcome,
+            };
+            let failed = outcome.answer().is_none();
+            if time_parse || failed {
+                let sample = Sample {
+                    part: None,
+                    outcome,
+                    elapsed,
+                    allocations: None,
+                };
+                if tx.send(sample).is_err() || failed {
+                    return;
+                }
+            }
+        }
+        let parsed = parsed.expect("parsed at least once");
+
+        for part in parts {
+            for _ in 0..repeat {
+                let start = Instant::now();
+                // parts only get a shared reference, a panic leaves the input as parsed
+                let solve = panic::AssertUnwindSafe(|| parsed.solve(part));
+                let (result, allocations) = alloc::measure(|| panic::catch_unwind(solve));
+                let elapsed = start.elapsed();
+
+                let outcome = match outcome(result) {
+                    Ok(answer) => Outcome::Solved(answer),
+                    Err(outcome) => outcome,
+                };
+                let solved = outcome.answer().is_some();
+                let sample = Sample {
+                    part: Some(part),
+                    outcome,
+                    elapsed,
+                    allocations,
+                };
+                if tx.send(sample).is_err() {
+                    return;
+                }
+                if !solved {
+                    break;
+                }
+            }
+        }
+    };
+
+    thread::Builder::new()
+        .name(SOLVER_THREAD.into())
+        .stack_size(STACK_SIZE)
+        .spawn(solver)
+        .map(|_| ())
+}
+
+/// Solves the parts of a puzzle on a thread of their own, so a panic or a runaway solution only
+/// affects its own records. The input is parsed once and shared by the parts, unless a part
+/// times out: the parts after it get a fresh thread and parse. Stack overflows still abort the
+/// whole process.
+pub fn solve(puzzle: &'static Puzzle, input: Arc<str>, options: &Options) -> Vec<Record> {
+    let repeat = options.repeat.max(1);
+    let mut records: Vec<Record> = options
+        .parts()
+        .map(|part| Record::new(puzzle, part, Outcome::Failed("not run".into())))
+        .collect();
+    let mut parse = Vec::with_capacity(repeat);
+    let params = options.params(puzzle);
+
+    let mut pending: Vec<Part> = options.parts().collect();
+    let mut time_parse = true;
+    while !pending.is_empty() {
+        let (tx, rx) = mpsc::channel();
+        if let Err(e) = spawn(
+            puzzle,
+            input.clone(),
+            params.clone(),
+            pending.clone(),
+            time_parse,
+            repeat,
+            tx,
+        ) {
+            for record in &mut records {
+                record.outcome = Outcome::Failed(format!("failed to start solver thread: {e}"));
+            }
+            break;
+        }
+        let parsing = time_parse;
+        time_parse = false;
+
+        loop {
+            let received = match options.timeout {
+                Some(timeout) => rx.recv_timeout(timeout),
+                None => rx.recv().map_err(|_| mpsc::RecvTimeoutError::Disconnected),
+            };
+            match received {
+                Ok(Sample {
+                    part: None,
+                    outcome,
+                    elapsed,
+                    ..
+                }) => {
+                    if outcome.answer().is_none() {
+                        for part in pending.drain(..) {
+                            let record = records.iter_mut().find(|r| r.part == part);
+                            record.expect("pending parts have records").outcome = outcome.clone();
+                        }
+                    }
+                    if parsing {
+                        parse.push(elapsed);
+                    }
+                }
+                Ok(sample) => {
+                    let part = sample.part.expect("parse samples are handled above");
+                    let record = records.iter_mut().find(|r| r.part == part);
+                    let record = record.expect("solved parts have records");
+                    let done =
+                        sample.outcome.answer().is_none() || record.samples.len() + 1 == repeat;
+                    record.outcome = sample.outcome;
+                    record.samples.push(sample.elapsed);
+                    record.allocations = sample.allocations;
+                    if done {
+                        pending.retain(|&p| p != part);
+                    }
+                }
+                Err(mpsc::RecvTimeoutError::Timeout) => {
+                    // while parsing, every part is stuck behind it
+                    let timed_out = match parsing && parse.len() < repeat {
+                        true => std::mem::take(&mut pending),
+                        false => vec![pending.remove(0)],
+                    };
+                    for record in records.iter_mut().filter(|r| timed_out.contains(&r.part)) {
+                        record.outcome = Outcome::TimedOut;
+                    }
+                    break;
+                }
+                Err(mpsc::RecvTimeoutError::Disconnected) => {
+                    pending.clear();
+                    break;
+                }
+            }
+        }
+    }
+
+    for record in &mut records {
+        record.parse = parse.clone();
+    }
+    records
+}
+
+/// Checks the options against the puzzles to solve and builds the pool their parts are solved on
+pub fn pool(puzzles: &[&'static Puzzle], options: &Options) -> eyre::Result<rayon::ThreadPool> {
+    for param in &options.params {
+        if !puzzles.iter().any(|p| p.params.contains(&param.name.as_str())) {
+            eyre::bail!("none of the selected puzzles has a parameter {:?}", param.name);
+        }
+    }
+    quiet_solver_panics();
+
+    Ok(rayon::ThreadPoolBuilder::new()
+        .num_threads(options.jobs.unwrap_or_default())
+        .build()?)
+}
+
+/// Records of the parts of a puzzle that could not be solved at all, e.g. without an input
+pub fn unsolved(puzzle: &'static Puzzle, options: &Options, error: &eyre::Report) -> Vec<Record> {
+    options
+        .parts()
+        .map(|part| Record::new(puzzle, part, Outcome::Failed(format!("{error:#}"))))
+        .collect()
+}
+
+/// A day the tests rely on being registered
+#[cfg(test)]
+pub fn linked(year: u16, day: u8) -> &'static Puzzle {
+    aoc_util::solution::find(year, day).expect("the day is linked into the runner")
+}
+
+/// Solves every puzzle on a thread pool, records come back in puzzle order
+pub fn run(
+    puzzles: &[&'static Puzzle],
+    locator: &Locator,
+    options: &Options,
+) -> eyre::Result<Vec<Record>> {
+    Ok(pool(puzzles, options)?.install(|| {
+        puzzles
+            .par_iter()
+            .flat_map_iter(|&puzzle| match locator.load_puzzle(puzzle) {
+                Ok(input) => solve(puzzle, input.into(), options),
+                Err(e) => unsolved(puzzle, options, &e),
+            })
+            .collect()
+    }))
+}
+
+fn format_duration(d: Option<Duration>) -> String {
+    d.map(|d| format!("{d:.2?}")).unwrap_or_default()
+}
+
+/// Renders records as a table, with a total per year and a grand total of the median times
+pub fn table(records: &[Record], repeat: usize) -> Table {
+    let detailed = repeat > 1;
+    let mut columns = vec![
+        ("year", Align::Left),
+        ("day", Align::Right),
+        ("part", Align::Right),
+        ("title", Align::Left),
+        ("answer", Align::Left),
+    ];
+    if detailed {
+        columns.extend([("median", Align::Right), ("min", Align::Right)]);
+    } else {
+        columns.push(("time", Align::Right));
+    }
+    columns.push(("parse", Align::Right));
+    let allocations = records.iter().any(|r| r.allocations.is_some());
+    if allocations {
+        columns.extend([
+            ("allocs", Align::Right),
+            ("allocated", Align::Right),
+            ("peak", Align::Right),
+        ]);
+    }
+    let mut table = Table::new(&columns);
+
+    let same_day = |a: &&Record, b: &&Record| {
+        (a.puzzle.year, a.puzzle.day) == (b.puzzle.year, b.puzzle.day)
+    };
+    let total_row = |label: String, records: &[&Record]| {
+        let total: Duration = records.iter().filter_map(|r| r.median()).sum();
+        let parse: Duration = records
+            .chunk_by(same_day)
+            .filter_map(|day| day[0].parse_median())
+            .sum();
+        let mut cells = vec![label, String::new(), String::new(), String::new()];
+        cells.push(format!("{} parts", records.len()));
+        cells.push(format_duration(Some(total)));
+        if detailed {
+            cells.push(String::new());
+        }
+        cells.push(format_duration(Some(parse)));
+        cells
+    };
+
+    let several_days = records
+        .iter()
+        .any(|r| (r.puzzle.year, r.puzzle.day) != (records[0].puzzle.year, records[0].puzzle.day));
+
+    let years = records.chunk_by(|a, b| a.puzzle.year == b.puzzle.year);
+    for year in years {
+        for (i, record) in year.iter().enumerate() {
+            // parsing is shared by the parts, shown once per day
+            let first = i == 0 || !same_day(&&year[i - 1], &record);
+            let mut cells = vec![
+                record.puzzle.year.to_string(),
+                format!("{:02}", record.puzzle.day),
+                record.part.to_string(),
+                record.puzzle.title.to_string(),
+                record.outcome.to_string(),
+                format_duration(record.median()),
+            ];
+            if detailed {
+                cells.push(format_duration(record.min()));
+            }
+            cells.push(format_duration(record.parse_median().filter(|_| first)));
+            if let Some(stats) = record.allocations.filter(|_| allocations) {
+                cells.extend([
+                    stats.count.to_string(),
+                    alloc::format_bytes(stats.bytes),
+                    alloc::format_bytes(stats.peak),
+                ]);
+            }
+            table.row(cells);
+        }
+
+        if several_days {
+            table.separator();
+            let year: Vec<_> = year.iter().collect();
+            table.row(total_row(format!("{} total", year[0].puzzle.year), &year));
+            table.separator();
+        }
+    }
+
+    if records.iter().any(|r| r.puzzle.year != records[0].puzzle.year) {
+        let all: Vec<_> = records.iter().collect();
+        table.row(total_row("total".into(), &all));
+    }
+
+    table
+}
@@ -0,0 +1,194 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use eyre::WrapErr;
+
+const CARGO_TOML: &str = include_str!("../templates/Cargo.toml");
+const BENCHMARKS: &str = include_str!("../templates/benchmarks.rs");
+const COMMON: &str = include_str!("../templates/lib/common.rs");
+
+/// Starting point for a new day's `src/lib.rs`
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum Flavor {
+    /// Splits the input into lines, `part1`/`part2` return 0
+    #[default]
+    Plain,
+    /// Parses the input into a `Grid<char>`
+    Grid,
+    /// Parses lines of space separated numbers with nom
+    Nom,
+    /// Parses one `a-b` edge per line into a petgraph graph
+    Graph,
+}
+
+impl Flavor {
+    fn body(self) -> &'static str {
+        match self {
+            Flavor::Plain => include_str!("../templates/lib/plain.rs"),
+            Flavor::Grid => include_str!("../templates/lib/grid.rs"),
+            Flavor::Nom => include_str!("../templates/lib/nom.rs"),
+            Flavor::Graph => include_str!("../templates/lib/graph.rs"),
+        }
+    }
+
+    /// Placeholder test input the flavor's parser accepts
+    fn example(self) -> &'static str {
+        match self {
+            Flavor::Plain => "TEST INPUT",
+            Flavor::Grid => "..#\n#..\n...",
+            Flavor::Nom => "1 2 3\n4 5 6",
+            Flavor::Graph => "a-b\nb-c",
+        }
+    }
+
+    /// What `parse` returns, the day's `Solution::Input`
+    fn input(self) -> &'static str {
+        match self {
+            Flavor::Plain => "Vec<&'a str>",
+            Flavor::Grid => "Grid<char>",
+            Flavor::Nom => "Vec<Vec<u32>>",
+            Flavor::Graph => "UnGraphMap<&'a str, ()>",
+        }
+    }
+
+    fn dependencies(self) -> &'static str {
+        match self {
+            Flavor::Graph => "petgraph.workspace = true\n",
+            _ => "",
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct NewDay {
+    pub year: u16,
+    pub day: u8,
+    pub title: String,
+    pub flavor: Flavor,
+}
+
+impl NewDay {
+    pub fn package(&self) -> String {
+        format!("aoc{}day{:02}", self.year, self.day)
+    }
+
+    /// Relative to the workspace root
+    pub fn dir(&self) -> PathBuf {
+        PathBuf::from(format!("years/{}/{:02}", self.year, self.day))
+    }
+
+    fn render(&self, template: &str) -> String {
+        template
+            .replace("{{package}}", &self.package())
+            .replace("{{year}}", &self.year.to_string())
+            .replace("{{day}}", &self.day.to_string())
+            .replace("{{title}}", &format!("{:?}", self.title))
+            .replace("{{example}}", &format!("{:?}", self.flavor.example()))
+            .replace("{{input}}", self.flavor.input())
+            .replace("{{dependencies}}\n", self.flavor.dependencies())
+    }
+
+    /// Every file of the day crate, relative to its directory
+    pub fn files(&self) -> Vec<(&'static str, String)> {
+        vec![
+            ("Cargo.toml", self.render(CARGO_TOML)),
+            (
+                "src/lib.rs",
+                self.render(&format!("{}\n{COMMON}", self.flavor.body())),
+            ),
+            ("benches/benchmarks.rs", self.render(BENCHMARKS)),
+        ]
+    }
+}
+
+fn is_day_dependency(line: &str) -> bool {
+    line.starts_with("aoc") && line.contains("day") && line.contains("path =")
+}
+
+/// Adds a day crate to the runner's manifest, next to the other days and in order
+pub fn add_dependency(manifest: &str, package: &str, path: &str) -> String {
+    let entry = format!("{package} = {{ path = \"{path}\" }}");
+    let mut lines: Vec<&str> = manifest.lines().collect();
+
+    let days: Vec<usize> = (0..lines.len())
+        .filter(|&i| is_day_dependency(lines[i]))
+        .collect();
+    let at = days
+        .iter()
+        .find(|&&i| lines[i] > entry.as_str())
+        .copied()
+        .or_else(|| days.last().map(|i| i + 1))
+        .unwrap_or(lines.len());
+
+    lines.insert(at, &entry);
+    lines.join("\n") + "\n"
+}
+
+/// Writes the new day crate and links it into the runner, refusing to touch an existing day
+pub fn create(root: &Path, new: &NewDay, input: Option<&str>) -> eyre::Result<PathBuf> {
+    let dir = root.join(new.dir());
+    if dir.exists() {
+        eyre::bail!("{} already exists, refusing to overwrite it", dir.display());
+    }
+
+    for (file, content) in new.files() {
+        let path = dir.join(file);
+        fs::create_dir_all(path.parent().expect("files are inside the day directory"))?;
+        fs::write(&path, content)
+            .wrap_err_with(|| format!("failed to write {}", path.display()))?;
+    }
+    if let Some(input) = input {
+        fs::write(dir.join("input.txt"), input)?;
+    }
+
+    let manifest_path = root.join("aoc/Cargo.toml");
+    let manifest = fs::read_to_string(&manifest_path)?;
+    let path = Path::new("..").join(new.dir());
+    fs::write(
+        &manifest_path,
+        add_dependency(&manifest, &new.package(), &path.to_string_lossy()),
+    )?;
+
+    Ok(dir)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Flavor, NewDay, add_dependency};
+
+    #[test]
+    fn renders_every_placeholder() {
+        for flavor in [Flavor::Plain, Flavor::Grid, Flavor::Nom, Flavor::Graph] {
+            let new = NewDay {
+                year: 2025,
+                day: 3,
+                title: "Some \"quoted\" title".into(),
+                flavor,
+            };
+            for (file, content) in new.files() {
+                assert!(!content.contains("{{"), "{flavor:?} {file}:\n{content}");
+            }
+
+            let lib = &new.files()[1].1;
+            assert!(lib.contains("const YEAR: u16 = 2025;"));
+            assert!(lib.contains("const DAY: u8 = 3;"));
+            assert!(lib.contains(r#"const TITLE: &'static str = "Some \"quoted\" title";"#));
+        }
+    }
+
+    #[test]
+    fn dependency_is_inserted_in_order() {
+        let manifest = "[dependencies]\neyre.workspace = true\n\naoc2015day01 = { path = \"../years/2015/01\" }\naoc2025day01 = { path = \"../years/2025/01\" }\n";
+
+        assert_eq!(
+            add_dependency(manifest, "aoc2024day01", "../years/2024/01"),
+            "[dependencies]\neyre.workspace = true\n\naoc2015day01 = { path = \"../years/2015/01\" }\naoc2024day01 = { path = \"../years/2024/01\" }\naoc2025day01 = { path = \"../years/2025/01\" }\n"
+        );
+        assert!(
+            add_dependency(manifest, "aoc2025day02", "../years/2025/02")
+                .ends_with("aoc2025day01 = { path = \"../years/2025/01\" }\naoc2025day02 = { path = \"../years/2025/02\" }\n")
+        );
+    }
+}
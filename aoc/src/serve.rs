@@ -0,0 +1,251 @@
+//! A local HTTP API over the registered solutions, for tools that would rather not spawn cargo:
+//!
+//! - `GET /days` lists the registered solutions
+//! - `POST /solve/<year>/<day>` solves the input in the body, and returns one JSON [`Row`] of
+//!   answer and timings per part
+//!
+//! Inputs over [`MAX_INPUT`] bytes are refused with 413. A part that times out keeps its solver
+//! thread until it returns, so solves are refused with 503 while [`MAX_SOLVERS`] threads are
+//! still running.
+
+use std::{io::Read, sync::Arc};
+
+use aoc_util::{
+    server::{
+        Server,
+        tiny_http::{self, Method},
+    },
+    solution::{self, Puzzle},
+};
+
+use crate::{
+    output::{self, Row},
+    run::{self, Options},
+};
+
+/// Real inputs are a few dozen KiB at most
+pub const MAX_INPUT: u64 = 1024 * 1024;
+
+/// Solver threads allowed at once, counting those of parts that timed out
+pub const MAX_SOLVERS: usize = 64;
+
+/// Serves on a background thread until dropped, solving requests on a thread pool
+pub fn bind(addr: &str, options: Options) -> eyre::Result<Server> {
+    listen(addr, options, MAX_SOLVERS)
+}
+
+fn listen(addr: &str, options: Options, max_solvers: usize) -> eyre::Result<Server> {
+    let pool = run::pool(&[], &options)?;
+    let service = Arc::new(Service {
+        options,
+        revision: output::revision(),
+        max_solvers,
+    });
+    Server::bind(addr, move |mut request| {
+        let service = service.clone();
+        pool.spawn(move || {
+            let response = service.respond(&mut request);
+            let _ = request.respond(response);
+        });
+    })
+}
+
+type Response = tiny_http::Response<std::io::Cursor<Vec<u8>>>;
+
+/// A registered solution, as listed by `GET /days`
+#[derive(Debug, serde::Serialize)]
+struct Day {
+    year: u16,
+    day: u8,
+    title: &'static str,
+    /// Parts that only return a placeholder answer so far
+    stubs: Vec<u8>,
+    params: &'static [&'static str],
+}
+
+impl From<&Puzzle> for Day {
+    fn from(puzzle: &Puzzle) -> Self {
+        Self {
+            year: puzzle.year,
+            day: puzzle.day,
+            title: puzzle.title,
+            stubs: puzzle.stubs.iter().map(|p| p.number()).collect(),
+            params: puzzle.params,
+        }
+    }
+}
+
+struct Service {
+    options: Options,
+    revision: String,
+    max_solvers: usize,
+}
+
+impl Service {
+    fn respond(&self, request: &mut tiny_http::Request) -> Response {
+        let url = request.url().to_string();
+        let path = url.split('?').next().unwrap_or_default();
+        let segments: Vec<&str> = path.trim_matches('/').split('/').collect();
+        let method = request.method().clone();
+
+        match (&method, &segments[..]) {
+            (Method::Get, ["days"]) => {
+                let days: Vec<Day> = solution::all().into_iter().map(Day::from).collect();
+                json(200, &days)
+            }
+            (Method::Post, ["solve", year, day]) => self.solve(year, day, request),
+            (_, ["days"] | ["solve", _, _]) => {
+                error(405, format!("{method} is not allowed on {path}"))
+            }
+            _ => error(404, format!("nothing at {path}")),
+        }
+    }
+
+    fn solve(&self, year: &str, day: &str, request: &mut tiny_http::Request) -> Response {
+        let (Ok(year), Ok(day)) = (year.parse::<u16>(), day.parse::<u8>()) else {
+            return error(400, format!("invalid year {year:?} or day {day:?}"));
+        };
+        let Some(puzzle) = solution::find(year, day) else {
+            return error(404, format!("no solution registered for {year}/{day:02}"));
+        };
+        if request
+            .body_length()
+            .is_some_and(|len| len as u64 > MAX_INPUT)
+        {
+            return error(413, format!("inputs are limited to {MAX_INPUT} bytes"));
+        }
+        let mut input = String::new();
+        let mut reader = request.as_reader().take(MAX_INPUT + 1);
+        if let Err(e) = reader.read_to_string(&mut input) {
+            return error(400, format!("failed to read the input: {e}"));
+        }
+        if input.len() as u64 > MAX_INPUT {
+            return error(413, format!("inputs are limited to {MAX_INPUT} bytes"));
+        }
+        if run::solvers() >= self.max_solvers {
+            return error(
+                503,
+                "too many solves are still running, try again later".into(),
+            );
+        }
+
+        let records = run::solve(puzzle, input.into(), &self.options);
+        let rows: Vec<_> = records
+            .iter()
+            .map(|r| Row::new(r, &self.revision, None))
+            .collect();
+        json(200, &rows)
+    }
+}
+
+fn json(status: u16, body: &impl serde::Serialize) -> Response {
+    let body = serde_json::to_string(body).expect("responses serialize to JSON");
+    let content_type = tiny_http::Header::from_bytes("Content-Type", "application/json")
+        .expect("the header is valid");
+    tiny_http::Response::from_string(body)
+        .with_status_code(status)
+        .with_header(content_type)
+}
+
+fn error(status: u16, message: String) -> Response {
+    json(status, &serde_json::json!({ "error": message }))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use aoc_util::Part;
+
+    use super::{MAX_INPUT, bind, listen};
+    use crate::run::Options;
+
+    fn get(agent: &ureq::Agent, url: &str) -> eyre::Result<(u16, serde_json::Value)> {
+        let response = agent.get(url).call()?;
+        let status = response.status().as_u16();
+        Ok((
+            status,
+            serde_json::from_str(&response.into_body().read_to_string()?)?,
+        ))
+    }
+
+    fn post(agent: &ureq::Agent, url: &str, body: &str) -> eyre::Result<(u16, serde_json::Value)> {
+        let response = agent.post(url).send(body)?;
+        let status = response.status().as_u16();
+        Ok((
+            status,
+            serde_json::from_str(&response.into_body().read_to_string()?)?,
+        ))
+    }
+
+    #[test]
+    fn solves_over_localhost() -> eyre::Result<()> {
+        let options = Options {
+            jobs: Some(2),
+            ..Default::default()
+        };
+        let server = bind("127.0.0.1:0", options)?;
+        let url = server.url();
+        let config = ureq::Agent::config_builder()
+            .http_status_as_error(false)
+            .build();
+        let agent = ureq::Agent::new_with_config(config);
+
+        let (status, days) = get(&agent, &format!("{url}/days"))?;
+        assert_eq!(status, 200);
+        let days = days.as_array().expect("days are a list");
+        assert!(days.iter().any(|d| d["year"] == 2015 && d["day"] == 1));
+        let lights = days.iter().find(|d| d["year"] == 2015 && d["day"] == 18);
+        assert_eq!(lights.expect("2015/18 is listed")["params"][0], "steps");
+
+        let (status, parts) = post(&agent, &format!("{url}/solve/2015/1"), "()())")?;
+        assert_eq!(status, 200);
+        assert_eq!(parts[0]["part"], 1);
+        assert_eq!(parts[0]["answer"], "-1");
+        assert_eq!(parts[1]["answer"], "5");
+        assert!(parts[1]["duration_ns"].is_u64());
+
+        let (status, parts) = post(&agent, &format!("{url}/solve/2015/1"), "up")?;
+        assert_eq!(status, 200);
+        assert_eq!(parts[0]["success"], false);
+        assert!(parts[0]["error"].as_str().unwrap().starts_with("ERROR: "));
+
+        let (status, error) = post(&agent, &format!("{url}/solve/1999/30"), "")?;
+        assert_eq!(status, 404);
+        assert!(error["error"].is_string());
+        assert_eq!(get(&agent, &format!("{url}/solve/2015/1"))?.0, 405);
+        assert_eq!(get(&agent, &format!("{url}/elsewhere"))?.0, 404);
+        Ok(())
+    }
+
+    #[test]
+    fn refuses_large_inputs_and_runaway_solves() -> eyre::Result<()> {
+        let options = Options {
+            part: Some(Part::One),
+            jobs: Some(1),
+            timeout: Some(Duration::from_millis(10)),
+            ..Default::default()
+        };
+        let server = listen("127.0.0.1:0", options, 1)?;
+        let url = format!("{}/solve/2015/4", server.url());
+        let config = ureq::Agent::config_builder()
+            .http_status_as_error(false)
+            .build();
+        let agent = ureq::Agent::new_with_config(config);
+
+        let huge = "(".repeat(MAX_INPUT as usize + 1);
+        assert_eq!(post(&agent, &url, &huge)?.0, 413);
+
+        // the timed out part keeps its solver thread busy for a while, whoever else is solving
+        let mut statuses = vec![];
+        while !statuses.contains(&503) && statuses.len() < 3 {
+            let (status, parts) = post(&agent, &url, "pqrstuv")?;
+            if status == 200 {
+                assert_eq!(parts[0]["error"], "TIMEOUT");
+            }
+            statuses.push(status);
+        }
+        assert!(statuses.contains(&503), "{statuses:?}");
+        Ok(())
+    }
+}
@@ -0,0 +1,156 @@
+use std::{collections::BTreeMap, fmt::Display};
+
+use aoc_util::{
+    Part,
+    answers::Answers,
+    input::Locator,
+    solution::Puzzle,
+    store,
+    table::{Align, Table},
+};
+
+const DAYS: [&str; 25] = [
+    "1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14", "15", "16", "17",
+    "18", "19", "20", "21", "22", "23", "24", "25",
+];
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum State {
+    /// No crate for the day
+    #[default]
+    Missing,
+    /// Listed in the solution's `STUBS`
+    Stubbed,
+    /// Solved, but without a known answer to check against
+    Implemented,
+    /// Solved, and its input has a recorded answer; nothing is run, `verify` checks it
+    Answered,
+}
+
+impl State {
+    pub fn symbol(self) -> char {
+        match self {
+            State::Missing => '.',
+            State::Stubbed => '~',
+            State::Implemented => '+',
+            State::Answered => '=',
+        }
+    }
+}
+
+impl Display for State {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            State::Missing => write!(f, "missing"),
+            State::Stubbed => write!(f, "stubbed"),
+            State::Implemented => write!(f, "implemented"),
+            State::Answered => write!(f, "answer known"),
+        }
+    }
+}
+
+/// Both parts of every day of one event
+pub type Calendar = [[State; 2]; 25];
+
+/// The answers file of the day's input, days without an input have no answers
+pub fn answers(puzzle: &Puzzle, locator: &Locator) -> eyre::Result<Answers> {
+    match locator.answers_path(puzzle).ok().flatten() {
+        Some(path) => store::load_or_default(&path),
+        None => Ok(Answers::default()),
+    }
+}
+
+/// What is known about each part, judged by the answers file of the day's input
+pub fn states(puzzle: &Puzzle, locator: &Locator) -> eyre::Result<[State; 2]> {
+    let answers = answers(puzzle, locator)?;
+
+    Ok(Part::ALL.map(|part| {
+        if puzzle.stubs.contains(&part) {
+            State::Stubbed
+        } else if answers.get(part).is_some() {
+            State::Answered
+        } else {
+            State::Implemented
+        }
+    }))
+}
+
+/// One calendar per year, for `year` or else every year with a registered solution
+pub fn calendars(
+    puzzles: &[&Puzzle],
+    year: Option<u16>,
+    locator: &Locator,
+) -> eyre::Result<BTreeMap<u16, Calendar>> {
+    let mut calendars = BTreeMap::new();
+    if let Some(year) = year {
+        calendars.insert(year, Calendar::default());
+    }
+
+    for puzzle in puzzles.iter().filter(|p| year.is_none_or(|y| p.year == y)) {
+        let calendar = calendars
+            .entry(puzzle.year)
+            .or_insert_with(Calendar::default);
+        if let Some(day) = calendar.get_mut(usize::from(puzzle.day) - 1) {
+            *day = states(puzzle, locator)?;
+        }
+    }
+
+    Ok(calendars)
+}
+
+/// One row per year, one column per day showing both parts, then the count of each state
+pub fn table(calendars: &BTreeMap<u16, Calendar>) -> Table {
+    let mut columns = vec![("year", Align::Left)];
+    columns.extend(DAYS.map(|day| (day, Align::Left)));
+    columns.push(("implemented", Align::Right));
+    columns.push(("stubbed", Align::Right));
+    let mut table = Table::new(&columns);
+
+    for (year, calendar) in calendars {
+        let count = |state| calendar.iter().flatten().filter(|&&s| s == state).count();
+        let implemented = count(State::Answered) + count(State::Implemented);
+
+        let mut row = vec![year.to_string()];
+        row.extend(
+            calendar
+                .iter()
+                .map(|parts| parts.iter().map(|s| s.symbol()).collect::<String>()),
+        );
+        row.push(format!("{implemented}/50"));
+        row.push(count(State::Stubbed).to_string());
+        table.row(row);
+    }
+
+    table
+}
+
+pub fn legend() -> String {
+    [
+        State::Answered,
+        State::Implemented,
+        State::Stubbed,
+        State::Missing,
+    ]
+    .map(|state| format!("{} {state}", state.symbol()))
+    .join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::{Calendar, State, table};
+
+    #[test]
+    fn calendar_table() {
+        let mut calendar = Calendar::default();
+        calendar[0] = [State::Answered, State::Answered];
+        calendar[1] = [State::Answered, State::Stubbed];
+        calendar[2] = [State::Implemented, State::Implemented];
+
+        let table = table(&BTreeMap::from([(2015, calendar)])).to_string();
+        let row = table.lines().nth(2).unwrap();
+        assert!(row.starts_with("2015  ==  =~  ++  ..  .."), "{row}");
+        assert!(row.ends_with("5/50        1"), "{row}");
+    }
+}
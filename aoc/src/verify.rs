@@ -0,0 +1,148 @@
+use std::{
+    collections::{HashMap, hash_map::Entry},
+    fmt::Display,
+    path::PathBuf,
+};
+
+use aoc_util::{
+    answers::{Answers, Verdict},
+    input::Locator,
+    store,
+    table::{Align, Table},
+};
+
+use crate::run::Record;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Status {
+    Checked(Verdict),
+    /// There was no known answer, and the live one was saved
+    Recorded,
+    /// The part only returns a placeholder, which is neither checked nor recorded
+    Stubbed,
+    Failed,
+}
+
+impl Status {
+    pub fn is_ok(&self) -> bool {
+        matches!(
+            self,
+            Status::Checked(Verdict::Correct | Verdict::Unknown)
+                | Status::Recorded
+                | Status::Stubbed
+        )
+    }
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Status::Checked(Verdict::Correct) => write!(f, "ok"),
+            Status::Checked(Verdict::Wrong { expected }) => write!(f, "WRONG, expected {expected}"),
+            Status::Checked(Verdict::Unknown) => write!(f, "unknown"),
+            Status::Recorded => write!(f, "recorded"),
+            Status::Stubbed => write!(f, "stubbed"),
+            Status::Failed => write!(f, "FAILED"),
+        }
+    }
+}
+
+/// Compares every record against the answers file of its input, optionally saving answers
+/// for parts that have none yet
+pub fn verify(records: &[Record], locator: &Locator, record: bool) -> eyre::Result<Vec<Status>> {
+    let mut files: HashMap<(u16, u8), (Option<PathBuf>, Answers, bool)> = HashMap::new();
+    let mut statuses = Vec::with_capacity(records.len());
+
+    for r in records {
+        let key = (r.puzzle.year, r.puzzle.day);
+        let (path, answers, dirty) = match files.entry(key) {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => {
+                let path = locator.answers_path(r.puzzle).ok().flatten();
+                let answers = match &path {
+                    Some(path) => store::load_or_default(path)?,
+                    None => Answers::default(),
+                };
+                entry.insert((path, answers, false))
+            }
+        };
+
+        let status = match r.outcome.answer() {
+            Some(_) if r.puzzle.stubs.contains(&r.part) => Status::Stubbed,
+            Some(answer) => match answers.check(r.part, answer) {
+                Verdict::Unknown if record && path.is_some() => {
+                    answers.set(r.part, answer.to_string());
+                    *dirty = true;
+                    Status::Recorded
+                }
+                verdict => Status::Checked(verdict),
+            },
+            None => Status::Failed,
+        };
+        statuses.push(status);
+    }
+
+    for (path, answers, dirty) in files.into_values() {
+        if let (Some(path), true) = (path, dirty) {
+            store::save(&path, &answers)?;
+        }
+    }
+
+    Ok(statuses)
+}
+
+pub fn table(records: &[Record], statuses: &[Status]) -> Table {
+    let mut table = Table::new(&[
+        ("year", Align::Left),
+        ("day", Align::Right),
+        ("part", Align::Right),
+        ("title", Align::Left),
+        ("answer", Align::Left),
+        ("status", Align::Left),
+    ]);
+
+    for (record, status) in records.iter().zip(statuses) {
+        table.row(vec![
+            record.puzzle.year.to_string(),
+            format!("{:02}", record.puzzle.day),
+            record.part.to_string(),
+            record.puzzle.title.to_string(),
+            record.outcome.to_string(),
+            status.to_string(),
+        ]);
+    }
+
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_util::{Part, answers::Answers, input::Locator, store};
+
+    use super::{Status, verify};
+    use crate::run::{Outcome, Record, linked};
+
+    #[test]
+    fn stubs_are_not_recorded() -> eyre::Result<()> {
+        let puzzle = linked(2015, 8);
+        assert_eq!(puzzle.stubs, [Part::Two]);
+        let dir = tempfile::tempdir()?;
+        let dir = dir.path();
+        let input = dir.join("08.txt");
+        std::fs::write(&input, "\"\"")?;
+
+        let record = |part, answer: &str| Record::new(puzzle, part, Outcome::Solved(answer.into()));
+        let locator = Locator {
+            path: Some(input.clone()),
+            ..Default::default()
+        };
+        let records = [record(Part::One, "2"), record(Part::Two, "0")];
+        let statuses = verify(&records, &locator, true)?;
+        assert_eq!(statuses, [Status::Recorded, Status::Stubbed]);
+
+        let answers: Answers = store::load_or_default(&Answers::sidecar(&input))?;
+        assert_eq!(answers.part1.as_deref(), Some("2"));
+        assert_eq!(answers.part2, None);
+        Ok(())
+    }
+}
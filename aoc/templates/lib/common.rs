@@ -0,0 +1,29 @@
+pub struct Day;
+
+impl aoc_util::Solution for Day {
+    const YEAR: u16 = {{year}};
+    const DAY: u8 = {{day}};
+    const TITLE: &'static str = {{title}};
+    /// Remove each part from here once it is solved
+    const STUBS: &'static [aoc_util::Part] = &aoc_util::Part::ALL;
+
+    type Input<'a> = {{input}};
+    type Params = ();
+
+    fn parse(input: &str) -> eyre::Result<Self::Input<'_>> {
+        parse(input)
+    }
+    fn part1(input: &Self::Input<'_>, _: &Self::Params) -> eyre::Result<impl std::fmt::Display> {
+        part1(input)
+    }
+    fn part2(input: &Self::Input<'_>, _: &Self::Params) -> eyre::Result<impl std::fmt::Display> {
+        part2(input)
+    }
+}
+aoc_util::register!(Day);
+
+// replace `_` with the answers from the puzzle text to turn the tests on
+aoc_util::examples! {
+    Day;
+    example: {{example}} => (_, _),
+}
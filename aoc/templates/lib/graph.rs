@@ -0,0 +1,20 @@
+use petgraph::graphmap::UnGraphMap;
+
+/// One `a-b` edge per line
+pub fn parse(input: &str) -> eyre::Result<UnGraphMap<&str, ()>> {
+    let edges = input
+        .lines()
+        .map(|line| {
+            line.split_once('-')
+                .ok_or_else(|| eyre::format_err!("not an edge: {line}"))
+        })
+        .collect::<eyre::Result<Vec<_>>>()?;
+    Ok(UnGraphMap::from_edges(edges))
+}
+
+pub fn part1(_graph: &UnGraphMap<&str, ()>) -> eyre::Result<usize> {
+    Ok(0)
+}
+pub fn part2(_graph: &UnGraphMap<&str, ()>) -> eyre::Result<usize> {
+    Ok(0)
+}
@@ -0,0 +1,14 @@
+use std::str::FromStr;
+
+use aoc_util::grid::*;
+
+pub fn parse(input: &str) -> eyre::Result<Grid<char>> {
+    Ok(Grid::from_str(input)?)
+}
+
+pub fn part1(_grid: &Grid<char>) -> eyre::Result<usize> {
+    Ok(0)
+}
+pub fn part2(_grid: &Grid<char>) -> eyre::Result<usize> {
+    Ok(0)
+}
@@ -0,0 +1,24 @@
+use aoc_util::parse::{
+    nom::{
+        IResult, Parser,
+        character::complete::{newline, space1},
+        multi::separated_list1,
+    },
+    parse_num,
+};
+
+fn lines(input: &str) -> IResult<&str, Vec<Vec<u32>>> {
+    separated_list1(newline, separated_list1(space1, parse_num)).parse(input)
+}
+
+pub fn parse(input: &str) -> eyre::Result<Vec<Vec<u32>>> {
+    let (_, lines) = lines(input).map_err(|e| eyre::format_err!("parse error: {e}"))?;
+    Ok(lines)
+}
+
+pub fn part1(_lines: &[Vec<u32>]) -> eyre::Result<u32> {
+    Ok(0)
+}
+pub fn part2(_lines: &[Vec<u32>]) -> eyre::Result<u32> {
+    Ok(0)
+}
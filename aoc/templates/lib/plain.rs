@@ -0,0 +1,10 @@
+pub fn parse(input: &str) -> eyre::Result<Vec<&str>> {
+    Ok(input.lines().collect())
+}
+
+pub fn part1(_lines: &[&str]) -> eyre::Result<u32> {
+    Ok(0)
+}
+pub fn part2(_lines: &[&str]) -> eyre::Result<u32> {
+    Ok(0)
+}
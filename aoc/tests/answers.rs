@@ -0,0 +1,32 @@
+//! Regression tests against the known answers of every day's real input, generated by build.rs
+
+use aoc_util::{Part, answers::Answers, input::Locator, solution, store};
+
+include!(concat!(env!("OUT_DIR"), "/days.rs"));
+
+fn check(year: u16, day: u8) -> eyre::Result<()> {
+    // some solutions need more than the default stack of a test thread in debug builds
+    std::thread::Builder::new()
+        .stack_size(256 * 1024 * 1024)
+        .spawn(move || check_on_thread(year, day))?
+        .join()
+        .unwrap_or_else(|panic| std::panic::resume_unwind(panic))
+}
+
+fn check_on_thread(year: u16, day: u8) -> eyre::Result<()> {
+    let puzzle = solution::find(year, day).expect("day is registered");
+    let locator = Locator::from_env();
+
+    let input = locator.load_puzzle(puzzle)?;
+    let path = locator.answers_path(puzzle)?.expect("input is a file");
+    let answers: Answers = store::load_or_default(&path)?;
+
+    for part in Part::ALL {
+        if let Some(expected) = answers.get(part) {
+            assert_eq!(puzzle.solve(part, &input)?, expected, "{puzzle} part {part}");
+        }
+    }
+    Ok(())
+}
+
+include!(concat!(env!("OUT_DIR"), "/answer_tests.rs"));
@@ -1,30 +1,278 @@
+use aoc_util::{
+    Part,
+    answers::Answers,
+    client::{BASE_URI, BASE_URL_ENV, Client, SESSION_ENV},
+    input::{Locator, Source},
+    leaderboard::Leaderboard,
+    profile::{Config, PROFILE_ENV, Profile},
+    puzzle, store,
+    submit::{Attempts, Response},
+    unlock,
+};
 use clap::Parser;
-use std::path::PathBuf;
-
-const BASE_URI: &str = "https://adventofcode.com";
+use std::{
+    io::Write,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime},
+};
 
 #[derive(clap::Parser, Debug)]
+#[clap(args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
 struct Args {
-    year: i16,
-    day: i8,
+    #[clap(subcommand)]
+    command: Option<Command>,
+
+    #[clap(required = true, value_parser = year())]
+    year: Option<u16>,
+    #[clap(required = true, value_parser = day())]
+    day: Option<u8>,
+
+    #[clap(short, long, env = SESSION_ENV, global = true)]
+    session: Option<String>,
+
+    /// Talk to this server instead of adventofcode.com, like a local mock-aoc
+    #[clap(long, env = BASE_URL_ENV, default_value = BASE_URI, global = true)]
+    base_url: String,
 
-    #[clap(short, long, env = "AOC_SESSION")]
-    session: String,
+    /// Use the session and input storage of this profile from the config file
+    #[clap(short = 'P', long, env = PROFILE_ENV, global = true)]
+    profile: Option<String>,
 
+    /// Write the input here instead of stdout
     #[clap(short, long)]
     out: Option<PathBuf>,
+
+    /// Fetch the input again even if it is cached
+    #[clap(short, long)]
+    force: bool,
+
+    /// Save the input where the runner finds it: in the profile's inputs, or as the day
+    /// crate's input.txt
+    #[clap(short = 'S', long, conflicts_with = "out")]
+    store: bool,
+
+    /// Save the examples of the puzzle page to `examples/NN.txt` in the day crate
+    #[clap(short, long)]
+    examples: bool,
+
+    /// Write the puzzle statement to `PUZZLE.md` in the day crate, appending part 2 once
+    /// it is unlocked
+    #[clap(short, long)]
+    puzzle: bool,
+
+    /// Count down to the puzzle's release instead of refusing to fetch it early
+    #[clap(short, long)]
+    wait: bool,
+}
+
+#[derive(clap::Subcommand, Debug)]
+enum Command {
+    /// Submit an answer, unless earlier attempts show it is wrong
+    Submit {
+        #[clap(value_parser = year())]
+        year: u16,
+        #[clap(value_parser = day())]
+        day: u8,
+        part: Part,
+        answer: String,
+    },
+    /// Show a private leaderboard, fetched at most once every 15 minutes
+    Leaderboard {
+        #[clap(value_parser = year())]
+        year: u16,
+        /// The number at the end of the leaderboard's URL
+        id: u64,
+        /// Show when each member got the stars of this day instead of the standings
+        #[clap(short, long, value_parser = day())]
+        day: Option<u8>,
+    },
+}
+
+fn year() -> impl clap::builder::TypedValueParser<Value = u16> {
+    clap::value_parser!(u16).range(i64::from(unlock::FIRST_YEAR)..)
+}
+
+fn day() -> impl clap::builder::TypedValueParser<Value = u8> {
+    let days = unlock::DAYS;
+    clap::value_parser!(u8).range(i64::from(*days.start())..=i64::from(*days.end()))
+}
+
+fn day_dir(year: u16, day: u8) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join(format!("../years/{year}/{day:02}"))
 }
 
 fn main() -> eyre::Result<()> {
     let args = Args::parse();
+    // an explicit session wins over the default profile, but not over a selected one
+    let config = Config::from_env()?;
+    let profile = match (&args.profile, &args.session) {
+        (Some(name), _) => Some(config.profile(name)?),
+        (None, Some(_)) => None,
+        (None, None) => config.select(None)?,
+    };
+    let mut client = match (profile, args.session) {
+        (Some(profile), _) => profile.client(),
+        (None, Some(session)) => Client::new(session),
+        (None, None) => eyre::bail!("pass --session, set {SESSION_ENV} or configure a profile"),
+    };
+    client = client.with_base_uri(args.base_url);
+    client.force = args.force;
+
+    match args.command {
+        Some(Command::Submit {
+            year,
+            day,
+            part,
+            answer,
+        }) => return submit(&client, profile, year, day, part, &answer),
+        Some(Command::Leaderboard { year, id, day }) => {
+            let leaderboard = Leaderboard::parse(&client.leaderboard(year, id)?)?;
+            match day {
+                Some(day) => print!("{}", leaderboard.day_table(day)),
+                None => print!("{}", leaderboard.table()),
+            }
+            return Ok(());
+        }
+        None => {}
+    }
+    let (year, day) = (
+        args.year.expect("required without a subcommand"),
+        args.day.expect("required without a subcommand"),
+    );
+
+    if let Some(left) = unlock::remaining(year, day, SystemTime::now()) {
+        if !args.wait {
+            eyre::bail!(
+                "{year}/{day:02} is not unlocked yet, it unlocks in {} at midnight US Eastern; \
+                 pass --wait to wait for it",
+                unlock::countdown(left)
+            );
+        }
+        wait_for_unlock(year, day)?;
+    }
+    // right after release the site may still be catching up
+    let fetch = |f: &dyn Fn() -> eyre::Result<String>| match args.wait {
+        true => unlock::retry(f),
+        false => f(),
+    };
+
+    let day_dir = day_dir(year, day);
+    let page = match args.examples || args.puzzle {
+        true => fetch(&|| client.puzzle(year, day))?,
+        false => String::new(),
+    };
+
+    if args.examples {
+        let examples = puzzle::examples(&page);
+        if examples.is_empty() {
+            eprintln!("no examples on the puzzle page");
+        }
+
+        let paths = puzzle::write_examples(&day_dir.join("examples"), &examples)?;
+        for (path, example) in paths.iter().zip(&examples) {
+            let name = path.strip_prefix(&day_dir).unwrap_or(path);
+            println!("{} ({} lines)", name.display(), example.lines().count());
+            for line in puzzle::preview(example, 4, 60).lines() {
+                println!("    {line}");
+            }
+        }
+    }
+
+    if args.puzzle {
+        update_statement(&day_dir, &page)?;
+    }
+
+    if args.out.is_some() || args.store || !(args.examples || args.puzzle) {
+        let input = fetch(&|| client.input(year, day))?;
+
+        let out = match (args.out, args.store, profile) {
+            (Some(out), _, _) => Some(out),
+            (None, true, Some(profile)) => Some(profile.input_path(year, day)),
+            (None, true, None) => Some(day_dir.join("input.txt")),
+            (None, false, _) => None,
+        };
+        match out {
+            Some(out) => {
+                if let Some(dir) = out.parent() {
+                    std::fs::create_dir_all(dir)?;
+                }
+                std::fs::write(&out, input)?;
+                eprintln!("saved {}", out.display());
+            }
+            None => print!("{input}"),
+        }
+    }
+
+    Ok(())
+}
+
+/// Shows a countdown on stderr until the puzzle unlocks
+fn wait_for_unlock(year: u16, day: u8) -> eyre::Result<()> {
+    let mut stderr = std::io::stderr();
+    while let Some(left) = unlock::remaining(year, day, SystemTime::now()) {
+        write!(
+            stderr,
+            "\r{year}/{day:02} unlocks in {} ",
+            unlock::countdown(left)
+        )?;
+        stderr.flush()?;
+        std::thread::sleep(left.min(Duration::from_secs(1)));
+    }
+    writeln!(stderr, "\r{year}/{day:02} is unlocked         ")?;
+    Ok(())
+}
+
+fn update_statement(day_dir: &Path, page: &str) -> eyre::Result<()> {
+    let path = day_dir.join("PUZZLE.md");
+    let existing = std::fs::read_to_string(&path).unwrap_or_default();
+    match puzzle::append_statement(&existing, &puzzle::statement(page)) {
+        Some(statement) => {
+            std::fs::write(&path, statement)?;
+            println!("updated {}", path.display());
+        }
+        None => println!("{} is up to date", path.display()),
+    }
+    Ok(())
+}
+
+/// Attempts are kept next to the input they were computed from, correct answers also go
+/// to its answers file
+fn submit(
+    client: &Client,
+    profile: Option<&Profile>,
+    year: u16,
+    day: u8,
+    part: Part,
+    answer: &str,
+) -> eyre::Result<()> {
+    let day_dir = day_dir(year, day);
+    let locator = profile.map_or_else(Locator::from_env, Profile::locator);
+    let input = match locator.locate(year, day, &day_dir)? {
+        Source::File(path) => path,
+        Source::Stdin => unreachable!("no explicit input path was given"),
+    };
+
+    let attempts_path = Attempts::sidecar(&input);
+    let mut attempts: Attempts = store::load_or_default(&attempts_path)?;
+    if let Some(reason) = attempts.refusal(part, answer) {
+        eyre::bail!("not submitting: {reason}");
+    }
+
+    let response = Response::parse(&client.submit(year, day, part, answer)?)?;
+    println!("{year}/{day:02} part {part}, {answer}: {response}");
+
+    attempts.record(part, answer, response);
+    store::save(&attempts_path, &attempts)?;
 
-    let response = ureq::get(format!("{BASE_URI}/{}/day/{}/input", args.year, args.day))
-        .header("Cookie", format!("session={}", args.session))
-        .call()?;
-    let input = response.into_body().read_to_string()?;
+    if response == Response::Correct {
+        let answers_path = Answers::sidecar(&input);
+        let mut answers: Answers = store::load_or_default(&answers_path)?;
+        answers.set(part, answer.to_string());
+        store::save(&answers_path, &answers)?;
 
-    if let Some(out) = args.out {
-        std::fs::write(out, input)?;
+        if part == Part::One && day_dir.join("PUZZLE.md").is_file() {
+            update_statement(&day_dir, &client.puzzle(year, day)?)?;
+        }
     }
 
     Ok(())
@@ -0,0 +1,32 @@
+use aoc_util::{client::SESSION_ENV, mock::MockServer};
+use clap::Parser;
+use std::path::PathBuf;
+
+/// Serves puzzles, inputs, answers and leaderboards from fixture files, point getinput at it
+/// with `--base-url`
+#[derive(clap::Parser, Debug)]
+struct Args {
+    #[clap(short, long, default_value_t = 8080)]
+    port: u16,
+
+    /// The only session cookie that is logged in
+    #[clap(short, long, env = SESSION_ENV)]
+    session: String,
+
+    #[clap(short, long, default_value = concat!(env!("CARGO_MANIFEST_DIR"), "/fixtures"))]
+    fixtures: PathBuf,
+}
+
+fn main() -> eyre::Result<()> {
+    let args = Args::parse();
+
+    let server = MockServer::bind(
+        &format!("127.0.0.1:{}", args.port),
+        &args.fixtures,
+        args.session,
+    )?;
+    eprintln!("serving {} on {}", args.fixtures.display(), server.url());
+    server.wait();
+
+    Ok(())
+}
@@ -0,0 +1,131 @@
+use std::path::{Path, PathBuf};
+
+use crate::{Part, store};
+
+/// Confirmed answers for one input, stored as TOML next to it:
+///
+/// ```toml
+/// part1 = "1234"
+/// part2 = "5678"
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct Answers {
+    #[serde(
+        default,
+        deserialize_with = "answer",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub part1: Option<String>,
+    #[serde(
+        default,
+        deserialize_with = "answer",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub part2: Option<String>,
+    /// Too slow on the real input to check in debug builds, the regression test is ignored
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub slow: bool,
+}
+
+/// Answers are compared as text, but accept bare numbers too
+fn answer<'de, D: serde::Deserializer<'de>>(d: D) -> Result<Option<String>, D::Error> {
+    #[derive(serde::Deserialize)]
+    #[serde(untagged)]
+    enum Answer {
+        Text(String),
+        Number(i64),
+    }
+
+    Ok(Some(match serde::Deserialize::deserialize(d)? {
+        Answer::Text(s) => s,
+        Answer::Number(n) => n.to_string(),
+    }))
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Wrong { expected: String },
+    Unknown,
+}
+
+impl Answers {
+    /// The answers file belonging to an input: `answers.toml` beside a day's `input.txt`,
+    /// `<name>.answers` beside any other `<name>.txt`
+    pub fn sidecar(input: &Path) -> PathBuf {
+        store::sidecar(input, "answers")
+    }
+
+    pub fn get(&self, part: Part) -> Option<&str> {
+        match part {
+            Part::One => self.part1.as_deref(),
+            Part::Two => self.part2.as_deref(),
+        }
+    }
+
+    pub fn set(&mut self, part: Part, answer: String) {
+        match part {
+            Part::One => self.part1 = Some(answer),
+            Part::Two => self.part2 = Some(answer),
+        }
+    }
+
+    pub fn check(&self, part: Part, answer: &str) -> Verdict {
+        match self.get(part) {
+            Some(expected) if expected == answer => Verdict::Correct,
+            Some(expected) => Verdict::Wrong {
+                expected: expected.to_string(),
+            },
+            None => Verdict::Unknown,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::{Answers, Verdict};
+    use crate::Part;
+
+    #[test]
+    fn parse() -> eyre::Result<()> {
+        let answers: Answers = toml::from_str("part1 = \"abc\"\npart2 = 42\n")?;
+        assert_eq!(answers.check(Part::One, "abc"), Verdict::Correct);
+        assert_eq!(answers.check(Part::Two, "42"), Verdict::Correct);
+        assert_eq!(
+            answers.check(Part::Two, "41"),
+            Verdict::Wrong {
+                expected: "42".into()
+            }
+        );
+        assert!(!answers.slow);
+
+        let answers: Answers = toml::from_str("part1 = \"1\"\nslow = true\n")?;
+        assert_eq!(answers.check(Part::Two, "1"), Verdict::Unknown);
+        assert!(answers.slow);
+        Ok(())
+    }
+
+    #[test]
+    fn round_trip() -> eyre::Result<()> {
+        let mut answers = Answers::default();
+        answers.set(Part::Two, "7".into());
+        let s = toml::to_string(&answers)?;
+        assert_eq!(s, "part2 = \"7\"\n");
+        assert_eq!(toml::from_str::<Answers>(&s)?, answers);
+        Ok(())
+    }
+
+    #[test]
+    fn sidecar() {
+        assert_eq!(
+            Answers::sidecar(Path::new("years/2024/06/input.txt")),
+            Path::new("years/2024/06/answers.toml")
+        );
+        assert_eq!(
+            Answers::sidecar(Path::new("inputs/2024/06.txt")),
+            Path::new("inputs/2024/06.answers")
+        );
+    }
+}
@@ -0,0 +1,338 @@
+use std::{
+    path::PathBuf,
+    time::{Duration, SystemTime},
+};
+
+use eyre::WrapErr;
+
+use crate::{Part, store};
+
+pub const BASE_URI: &str = "https://adventofcode.com";
+
+/// Environment variable holding the session cookie of an adventofcode.com login
+pub const SESSION_ENV: &str = "AOC_SESSION";
+
+/// Environment variable with contact details (an email or repository) for the User-Agent
+pub const CONTACT_ENV: &str = "AOC_CONTACT";
+
+/// Environment variable pointing the client at another server, like the [mock](crate::mock)
+pub const BASE_URL_ENV: &str = "AOC_BASE_URL";
+
+/// Environment variable overriding where fetched inputs are cached
+pub const CACHE_ENV: &str = "AOC_CACHE";
+
+/// Requests from all processes sharing a cache are spaced at least this far apart, when
+/// talking to the real site
+pub const MIN_INTERVAL: Duration = Duration::from_secs(5);
+
+/// The leaderboard API asks not to be polled more often than this
+pub const LEADERBOARD_TTL: Duration = Duration::from_secs(15 * 60);
+
+/// The site answered with something other than what was asked for
+#[derive(Debug, PartialEq, Eq, derive_more::Display, derive_more::Error)]
+pub enum Error {
+    #[display("not logged in, the session in {SESSION_ENV} is missing or expired")]
+    LoggedOut,
+    #[display("the puzzle is not unlocked yet")]
+    Locked,
+    #[display("unexpected HTTP status {_0}")]
+    Status(#[error(not(source))] u16),
+}
+
+/// Talks to adventofcode.com on behalf of one logged in session
+#[derive(Clone, Debug)]
+pub struct Client {
+    session: String,
+    agent: ureq::Agent,
+    base_uri: String,
+    /// Inputs are kept in `<cache>/<account>/<year>/<day>.txt`, and the time of the last
+    /// request in `<cache>/last-request`; other servers get a `<cache>/hosts/<host>` of their
+    /// own, so their inputs are never mistaken for the site's
+    pub cache: Option<PathBuf>,
+    /// Fetch inputs again even when they are cached
+    pub force: bool,
+    pub min_interval: Duration,
+}
+
+impl Client {
+    pub fn new(session: impl Into<String>) -> Self {
+        let config = ureq::Agent::config_builder()
+            .http_status_as_error(false)
+            .user_agent(user_agent(std::env::var(CONTACT_ENV).ok().as_deref()))
+            .build();
+
+        let base_uri = std::env::var(BASE_URL_ENV)
+            .ok()
+            .filter(|v| !v.is_empty())
+            .unwrap_or_else(|| BASE_URI.into());
+
+        Self {
+            session: session.into(),
+            agent: ureq::Agent::new_with_config(config),
+            base_uri: String::new(),
+            cache: store::user_path(CACHE_ENV, "XDG_CACHE_HOME", ".cache", "aoc"),
+            force: false,
+            min_interval: MIN_INTERVAL,
+        }
+        .with_base_uri(base_uri)
+    }
+
+    /// Talks to another server instead; only adventofcode.com itself is throttled
+    pub fn with_base_uri(mut self, base_uri: impl Into<String>) -> Self {
+        self.base_uri = base_uri.into().trim_end_matches('/').to_string();
+        self.min_interval = match self.base_uri == BASE_URI {
+            true => MIN_INTERVAL,
+            false => Duration::ZERO,
+        };
+        self
+    }
+
+    pub fn from_env() -> eyre::Result<Self> {
+        let session = std::env::var(SESSION_ENV)
+            .map_err(|_| eyre::format_err!("{SESSION_ENV} must be set to fetch from the site"))?;
+        Ok(Self::new(session))
+    }
+
+    fn get(&self, path: &str) -> eyre::Result<String> {
+        self.throttle()?;
+        let response = self
+            .agent
+            .get(format!("{}{path}", self.base_uri))
+            .header("Cookie", format!("session={}", self.session))
+            .call()?;
+        let status = response.status().as_u16();
+        check(status, response.into_body().read_to_string()?)
+    }
+
+    /// Where the inputs and leaderboards of this server are cached
+    fn cache_root(&self) -> Option<PathBuf> {
+        let dir = self.cache.as_ref()?;
+        Some(match self.base_uri == BASE_URI {
+            true => dir.clone(),
+            false => dir.join("hosts").join(
+                self.base_uri
+                    .split_once("://")
+                    .map_or(self.base_uri.as_str(), |(_, host)| host)
+                    .replace([':', '/'], "_"),
+            ),
+        })
+    }
+
+    /// Where [`Client::input`] caches the input of a day
+    pub fn cached_input(&self, year: u16, day: u8) -> Option<PathBuf> {
+        Some(
+            self.cache_root()?
+                .join(self.account())
+                .join(year.to_string())
+                .join(format!("{day:02}.txt")),
+        )
+    }
+
+    /// Cached, only the first call for a day reaches the site unless [`Client::force`] is set
+    pub fn input(&self, year: u16, day: u8) -> eyre::Result<String> {
+        let cached = self.cached_input(year, day);
+        if let Some(path) = cached.as_ref().filter(|path| !self.force && path.is_file()) {
+            return std::fs::read_to_string(path)
+                .wrap_err_with(|| format!("failed to read cached input {}", path.display()));
+        }
+
+        let input = self.get(&format!("/{year}/day/{day}/input"))?;
+        if let Some(path) = cached {
+            std::fs::create_dir_all(path.parent().expect("cached inputs are in a directory"))?;
+            std::fs::write(&path, &input)
+                .wrap_err_with(|| format!("failed to cache input in {}", path.display()))?;
+        }
+        Ok(input)
+    }
+
+    /// HTML of the puzzle page, including part 2 once part 1 is solved
+    pub fn puzzle(&self, year: u16, day: u8) -> eyre::Result<String> {
+        self.get(&format!("/{year}/day/{day}"))
+    }
+
+    /// JSON of a private leaderboard, reused from the cache for [`LEADERBOARD_TTL`]
+    pub fn leaderboard(&self, year: u16, id: u64) -> eyre::Result<String> {
+        let cached = self
+            .cache_root()
+            .map(|dir| dir.join("leaderboards").join(format!("{year}-{id}.json")));
+        if let Some(path) = &cached {
+            let age = std::fs::metadata(path)
+                .and_then(|m| m.modified())
+                .ok()
+                .and_then(|modified| modified.elapsed().ok());
+            if age.is_some_and(|age| age < LEADERBOARD_TTL) {
+                return Ok(std::fs::read_to_string(path)?);
+            }
+        }
+
+        let json = self.get(&format!("/{year}/leaderboard/private/view/{id}.json"))?;
+        if let Some(path) = cached {
+            std::fs::create_dir_all(path.parent().expect("leaderboards are in a directory"))?;
+            std::fs::write(&path, &json)?;
+        }
+        Ok(json)
+    }
+
+    /// Posts an answer and returns the page the site responds with, see
+    /// [`Response::parse`](crate::submit::Response::parse)
+    pub fn submit(&self, year: u16, day: u8, part: Part, answer: &str) -> eyre::Result<String> {
+        self.throttle()?;
+        let response = self
+            .agent
+            .post(format!("{}/{year}/day/{day}/answer", self.base_uri))
+            .header("Cookie", format!("session={}", self.session))
+            .send_form([
+                ("level", part.number().to_string().as_str()),
+                ("answer", answer),
+            ])?;
+        let status = response.status().as_u16();
+        check(status, response.into_body().read_to_string()?)
+    }
+
+    /// Stable name for the session, so accounts never share cached inputs
+    fn account(&self) -> String {
+        // FNV-1a, the std hashers are not stable across releases
+        let hash = self
+            .session
+            .bytes()
+            .fold(0xcbf29ce484222325u64, |hash, byte| {
+                (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+            });
+        format!("{hash:016x}")
+    }
+
+    /// Sleeps until [`Client::min_interval`] has passed since the last request
+    fn throttle(&self) -> eyre::Result<()> {
+        let Some(dir) = self.cache.as_ref().filter(|_| !self.min_interval.is_zero()) else {
+            return Ok(());
+        };
+        let stamp = dir.join("last-request");
+
+        let last = std::fs::read_to_string(&stamp)
+            .ok()
+            .and_then(|s| s.trim().parse().ok())
+            .map(|millis| SystemTime::UNIX_EPOCH + Duration::from_millis(millis));
+        let delay = throttle_delay(last, SystemTime::now(), self.min_interval);
+        if !delay.is_zero() {
+            std::thread::sleep(delay);
+        }
+
+        std::fs::create_dir_all(dir)?;
+        let now = SystemTime::now().duration_since(SystemTime::UNIX_EPOCH)?;
+        std::fs::write(&stamp, now.as_millis().to_string())?;
+        Ok(())
+    }
+}
+
+fn user_agent(contact: Option<&str>) -> String {
+    let version = env!("CARGO_PKG_VERSION");
+    match contact {
+        Some(contact) => format!("aoc-util/{version} (personal Advent of Code tooling; {contact})"),
+        None => format!("aoc-util/{version} (personal Advent of Code tooling)"),
+    }
+}
+
+fn throttle_delay(last: Option<SystemTime>, now: SystemTime, interval: Duration) -> Duration {
+    last.and_then(|last| (last + interval).duration_since(now).ok())
+        .unwrap_or_default()
+}
+
+/// Turns the site's error pages into errors, they are not served with consistent statuses
+fn check(status: u16, body: String) -> eyre::Result<String> {
+    if body.contains("Puzzle inputs differ by user")
+        || body.contains("please identify yourself")
+        || body.contains("You don't seem to be logged in")
+    {
+        Err(Error::LoggedOut)?
+    }
+    if body.contains("before it unlocks") || status == 404 {
+        Err(Error::Locked)?
+    }
+    if !(200..300).contains(&status) {
+        Err(Error::Status(status))?
+    }
+    Ok(body)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, SystemTime};
+
+    use super::{BASE_URI, Client, Error, MIN_INTERVAL, check, throttle_delay, user_agent};
+
+    fn error(status: u16, body: &str) -> Option<Error> {
+        check(status, body.to_string())
+            .err()
+            .and_then(|e| e.downcast().ok())
+    }
+
+    #[test]
+    fn error_pages() {
+        assert_eq!(
+            error(400, include_str!("../fixtures/input-logged-out.txt")),
+            Some(Error::LoggedOut)
+        );
+        assert_eq!(
+            error(200, include_str!("../fixtures/2015-07-logged-out.html")),
+            Some(Error::LoggedOut)
+        );
+        assert_eq!(
+            error(404, include_str!("../fixtures/input-locked.txt")),
+            Some(Error::Locked)
+        );
+        assert_eq!(error(500, "oops"), Some(Error::Status(500)));
+        assert!(check(200, "1 2 3\n".into()).is_ok());
+        assert!(check(200, include_str!("../fixtures/2015-07-part1.html").into()).is_ok());
+    }
+
+    #[test]
+    fn throttling() {
+        let now = SystemTime::UNIX_EPOCH + Duration::from_secs(1_000_000);
+        let interval = Duration::from_secs(5);
+
+        assert_eq!(throttle_delay(None, now, interval), Duration::ZERO);
+        assert_eq!(
+            throttle_delay(Some(now - Duration::from_secs(2)), now, interval),
+            Duration::from_secs(3)
+        );
+        assert_eq!(
+            throttle_delay(Some(now - Duration::from_secs(60)), now, interval),
+            Duration::ZERO
+        );
+    }
+
+    #[test]
+    fn only_the_site_is_throttled() {
+        let client = Client::new("session").with_base_uri("http://127.0.0.1:8080/");
+        assert_eq!(client.min_interval, Duration::ZERO);
+        let client = client.with_base_uri(BASE_URI);
+        assert_eq!(client.min_interval, MIN_INTERVAL);
+    }
+
+    #[test]
+    fn other_servers_are_cached_apart() {
+        let mut site = Client::new("session").with_base_uri(BASE_URI);
+        site.cache = Some("/cache".into());
+        let mock = site.clone().with_base_uri("http://127.0.0.1:8080");
+
+        let input = site.cached_input(2015, 7).unwrap();
+        assert!(input.starts_with("/cache") && input.ends_with("2015/07.txt"));
+        assert!(!input.starts_with("/cache/hosts"));
+        let input = mock.cached_input(2015, 7).unwrap();
+        assert!(
+            input.starts_with("/cache/hosts/127.0.0.1_8080"),
+            "{input:?}"
+        );
+    }
+
+    #[test]
+    fn identifies_itself() {
+        assert_eq!(
+            user_agent(Some("someone@example.com")),
+            format!(
+                "aoc-util/{} (personal Advent of Code tooling; someone@example.com)",
+                env!("CARGO_PKG_VERSION")
+            )
+        );
+    }
+}
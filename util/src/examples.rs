@@ -0,0 +1,180 @@
+use std::fmt::Display;
+
+use crate::{Part, Solution};
+
+fn answer<S: Solution>(input: &str, part: Part, params: &S::Params) -> String {
+    let input = S::parse(input).unwrap_or_else(|e| panic!("failed to parse the example: {e:?}"));
+    match part {
+        Part::One => S::part1(&input, params).map(|answer| answer.to_string()),
+        Part::Two => S::part2(&input, params).map(|answer| answer.to_string()),
+    }
+    .unwrap_or_else(|e| panic!("part {part} failed on the example: {e:?}"))
+}
+
+/// Solves `part` of an example and compares the answer as text, so `142` matches a part that
+/// returns `142u32` or `"142"`
+#[track_caller]
+pub fn check<S: Solution>(input: &str, part: Part, params: &S::Params, expected: impl Display) {
+    assert_eq!(answer::<S>(input, part, params), expected.to_string(), "part {part}");
+}
+
+/// Stands in for the test of a part whose example answer is not known yet, printing what the
+/// part returns when run with `--ignored --nocapture`
+pub fn show<S: Solution>(input: &str, part: Part, params: &S::Params) {
+    println!("part {part}: {}", answer::<S>(input, part, params));
+}
+
+/// Generates a test module per example of a day, at the root of the day's crate:
+///
+/// ```ignore
+/// aoc_util::examples! {
+///     Day;
+///     // both parts, `_` for an answer that is not known yet which ignores the test
+///     example: "1abc2\npqr3stu8vwx" => (142, _),
+///     // only one part
+///     words: "two1nine\neightwothree" => part2 = 281,
+///     // read from a file relative to the crate
+///     larger: file "examples/03.txt" => (4361, 467835),
+///     // with some of the day's `Params` changed from their defaults
+///     smaller { steps: 4 }: "#.#\n.#." => part1 = 3,
+/// }
+/// ```
+///
+/// The tests are `examples::<name>::part1` and `examples::<name>::part2`. Inputs can name
+/// anything in scope at the crate root, such as a `const INPUT`. Answers are compared as text,
+/// integers past the range of an `i32` need a suffix such as `u64`.
+#[macro_export]
+macro_rules! examples {
+    ($solution:ty; $($examples:tt)*) => {
+        #[cfg(test)]
+        mod examples {
+            #[allow(unused_imports)]
+            use super::*;
+
+            $crate::examples!(@example $solution; $($examples)*);
+        }
+    };
+
+    (@example $solution:ty;) => {};
+    (@example $solution:ty; $name:ident $({ $($field:ident: $value:expr),* $(,)? })?: file $path:literal => $($rest:tt)*) => {
+        $crate::examples!(
+            @answers $solution; $name; [$($($field: $value),*)?];
+            include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/", $path));
+            $($rest)*
+        );
+    };
+    (@example $solution:ty; $name:ident $({ $($field:ident: $value:expr),* $(,)? })?: $input:expr => $($rest:tt)*) => {
+        $crate::examples!(@answers $solution; $name; [$($($field: $value),*)?]; $input; $($rest)*);
+    };
+
+    (@answers $solution:ty; $name:ident; $params:tt; $input:expr; (_, _) $(, $($rest:tt)*)?) => {
+        $crate::examples!(@module $solution; $name; $params; $input; [part1 One _] [part2 Two _]);
+        $crate::examples!(@example $solution; $($($rest)*)?);
+    };
+    (@answers $solution:ty; $name:ident; $params:tt; $input:expr; (_, $two:expr) $(, $($rest:tt)*)?) => {
+        $crate::examples!(@module $solution; $name; $params; $input; [part1 One _] [part2 Two $two]);
+        $crate::examples!(@example $solution; $($($rest)*)?);
+    };
+    (@answers $solution:ty; $name:ident; $params:tt; $input:expr; ($one:expr, _) $(, $($rest:tt)*)?) => {
+        $crate::examples!(@module $solution; $name; $params; $input; [part1 One $one] [part2 Two _]);
+        $crate::examples!(@example $solution; $($($rest)*)?);
+    };
+    (@answers $solution:ty; $name:ident; $params:tt; $input:expr; ($one:expr, $two:expr) $(, $($rest:tt)*)?) => {
+        $crate::examples!(@module $solution; $name; $params; $input; [part1 One $one] [part2 Two $two]);
+        $crate::examples!(@example $solution; $($($rest)*)?);
+    };
+    (@answers $solution:ty; $name:ident; $params:tt; $input:expr; part1 = $one:expr $(, $($rest:tt)*)?) => {
+        $crate::examples!(@module $solution; $name; $params; $input; [part1 One $one]);
+        $crate::examples!(@example $solution; $($($rest)*)?);
+    };
+    (@answers $solution:ty; $name:ident; $params:tt; $input:expr; part2 = $two:expr $(, $($rest:tt)*)?) => {
+        $crate::examples!(@module $solution; $name; $params; $input; [part2 Two $two]);
+        $crate::examples!(@example $solution; $($($rest)*)?);
+    };
+
+    (@module $solution:ty; $name:ident; $params:tt; $input:expr; $([$test:ident $part:ident $($expected:tt)*])*) => {
+        mod $name {
+            #[allow(unused_imports)]
+            use super::*;
+
+            fn params() -> <$solution as $crate::Solution>::Params {
+                $crate::examples!(@params $solution; $params)
+            }
+
+            $($crate::examples!(@test $solution; $input; $test $part $($expected)*);)*
+        }
+    };
+
+    (@params $solution:ty; []) => {
+        Default::default()
+    };
+    (@params $solution:ty; [$($field:ident: $value:expr),*]) => {{
+        let mut params = <<$solution as $crate::Solution>::Params as Default>::default();
+        $(params.$field = $value;)*
+        params
+    }};
+
+    (@test $solution:ty; $input:expr; $test:ident $part:ident _) => {
+        #[test]
+        #[ignore = "the answer to this example is not known yet"]
+        fn $test() {
+            $crate::examples::show::<$solution>($input, $crate::Part::$part, &params());
+        }
+    };
+    (@test $solution:ty; $input:expr; $test:ident $part:ident $expected:expr) => {
+        #[test]
+        fn $test() {
+            $crate::examples::check::<$solution>($input, $crate::Part::$part, &params(), $expected);
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Part, Solution};
+
+    struct Example;
+    impl Solution for Example {
+        const YEAR: u16 = 1999;
+        const DAY: u8 = 2;
+        const TITLE: &'static str = "Example";
+
+        type Input<'a> = Vec<i64>;
+        type Params = Params;
+
+        fn parse(input: &str) -> eyre::Result<Self::Input<'_>> {
+            Ok(input
+                .split(',')
+                .map(str::parse)
+                .collect::<Result<_, _>>()?)
+        }
+        fn part1(input: &Self::Input<'_>, params: &Params) -> eyre::Result<impl std::fmt::Display> {
+            Ok(input.iter().sum::<i64>() + params.offset)
+        }
+        fn part2(input: &Self::Input<'_>, _: &Params) -> eyre::Result<impl std::fmt::Display> {
+            Ok(input.iter().product::<i64>())
+        }
+    }
+
+    crate::params! {
+        struct Params {
+            offset: i64 = 0,
+        }
+    }
+
+    const INPUT: &str = "1,2,-3";
+
+    crate::examples! {
+        Example;
+        both: INPUT => (0, -6),
+        unknown: "4,5" => (9, _),
+        second: "2,3" => part2 = 6,
+        offset { offset: 10 }: "2,3" => (15, 6),
+    }
+
+    #[test]
+    #[should_panic(expected = "part 1")]
+    fn wrong_answer() {
+        super::check::<Example>(INPUT, Part::One, &Params::default(), 1);
+    }
+}
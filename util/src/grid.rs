@@ -197,7 +197,7 @@ impl<T: PartialEq> Grid<T> {
             }
         }
 
-        return None;
+        None
     }
 }
 
@@ -0,0 +1,166 @@
+use std::{
+    io::Read,
+    path::{Path, PathBuf},
+};
+
+use crate::{Solution, answers::Answers, solution::Puzzle};
+
+/// Environment variable naming a directory of inputs laid out as `<year>/<day>.txt`
+pub const INPUTS_ENV: &str = "AOC_INPUTS";
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Source {
+    Stdin,
+    File(PathBuf),
+}
+
+/// Resolves the input for a day, in order of preference:
+/// an explicit path (`-` for stdin), the inputs directory, then the day crate's `input.txt`
+#[derive(Clone, Debug, Default)]
+pub struct Locator {
+    pub path: Option<PathBuf>,
+    pub inputs_dir: Option<PathBuf>,
+    /// Never fall back to the day crate's `input.txt`, which belongs to someone else when
+    /// inputs come from a profile
+    pub inputs_only: bool,
+}
+
+impl Locator {
+    pub fn from_env() -> Self {
+        Self {
+            path: None,
+            inputs_dir: std::env::var_os(INPUTS_ENV).map(PathBuf::from),
+            inputs_only: false,
+        }
+    }
+
+    pub fn locate(&self, year: u16, day: u8, manifest_dir: &Path) -> eyre::Result<Source> {
+        if let Some(path) = &self.path {
+            return Ok(match path.to_str() {
+                Some("-") => Source::Stdin,
+                _ => Source::File(path.clone()),
+            });
+        }
+
+        let candidates: Vec<_> = self
+            .inputs_dir
+            .iter()
+            .map(|dir| dir.join(year.to_string()).join(format!("{day:02}.txt")))
+            .chain((!self.inputs_only).then(|| manifest_dir.join("input.txt")))
+            .collect();
+
+        match candidates.iter().find(|p| p.is_file()) {
+            Some(path) => Ok(Source::File(path.clone())),
+            None => Err(eyre::format_err!(
+                "no input for {year}/{day:02}, looked for {}; fetch it with getinput or pass a path",
+                candidates
+                    .iter()
+                    .map(|p| p.display().to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )),
+        }
+    }
+
+    pub fn load(&self, year: u16, day: u8, manifest_dir: &Path) -> eyre::Result<String> {
+        read(&self.locate(year, day, manifest_dir)?)
+    }
+
+    pub fn load_puzzle(&self, puzzle: &Puzzle) -> eyre::Result<String> {
+        self.load(puzzle.year, puzzle.day, Path::new(puzzle.manifest_dir))
+    }
+
+    /// Where the answers to the located input are kept, there are none for stdin
+    pub fn answers_path(&self, puzzle: &Puzzle) -> eyre::Result<Option<PathBuf>> {
+        Ok(
+            match self.locate(puzzle.year, puzzle.day, Path::new(puzzle.manifest_dir))? {
+                Source::File(path) => Some(Answers::sidecar(&path)),
+                Source::Stdin => None,
+            },
+        )
+    }
+}
+
+pub fn read(source: &Source) -> eyre::Result<String> {
+    use eyre::WrapErr;
+
+    match source {
+        Source::Stdin => {
+            let mut input = String::new();
+            std::io::stdin()
+                .read_to_string(&mut input)
+                .wrap_err("failed to read input from stdin")?;
+            Ok(input)
+        }
+        Source::File(path) => std::fs::read_to_string(path)
+            .wrap_err_with(|| format!("failed to read input from {}", path.display())),
+    }
+}
+
+/// Loads the input of `S` using [`Locator::from_env`], for use from benches:
+/// `aoc_util::input::load::<Day>(env!("CARGO_MANIFEST_DIR"))`
+pub fn load<S: Solution>(manifest_dir: &str) -> eyre::Result<String> {
+    Locator::from_env().load(S::YEAR, S::DAY, Path::new(manifest_dir))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Locator, Source};
+
+    #[test]
+    fn explicit_path_wins() -> eyre::Result<()> {
+        let locator = Locator {
+            path: Some("elsewhere.txt".into()),
+            inputs_dir: Some("inputs".into()),
+            inputs_only: false,
+        };
+        assert_eq!(
+            locator.locate(2024, 6, "day".as_ref())?,
+            Source::File("elsewhere.txt".into())
+        );
+
+        let locator = Locator {
+            path: Some("-".into()),
+            ..Default::default()
+        };
+        assert_eq!(locator.locate(2024, 6, "day".as_ref())?, Source::Stdin);
+        Ok(())
+    }
+
+    #[test]
+    fn inputs_dir_before_default() -> eyre::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let dir = dir.path();
+        let manifest_dir = dir.join("day");
+        std::fs::create_dir_all(&manifest_dir)?;
+        std::fs::write(manifest_dir.join("input.txt"), "default")?;
+
+        let locator = Locator {
+            path: None,
+            inputs_dir: Some(dir.join("inputs")),
+            inputs_only: false,
+        };
+        assert_eq!(locator.load(2024, 6, &manifest_dir)?, "default");
+        let only = Locator {
+            inputs_only: true,
+            ..locator.clone()
+        };
+        assert!(only.load(2024, 6, &manifest_dir).is_err());
+
+        std::fs::create_dir_all(dir.join("inputs/2024"))?;
+        std::fs::write(dir.join("inputs/2024/06.txt"), "shared")?;
+        assert_eq!(locator.load(2024, 6, &manifest_dir)?, "shared");
+        Ok(())
+    }
+
+    #[test]
+    fn missing_input() {
+        let dir = tempfile::tempdir().unwrap();
+        let err = Locator::default()
+            .locate(2024, 6, dir.path())
+            .unwrap_err()
+            .to_string();
+        assert!(err.starts_with("no input for 2024/06"), "{err}");
+        assert!(err.contains("input.txt"), "{err}");
+    }
+}
@@ -0,0 +1,264 @@
+//! Private leaderboards, from `https://adventofcode.com/<year>/leaderboard/private/view/<id>.json`
+
+use std::{collections::BTreeMap, time::Duration};
+
+use crate::{
+    Part,
+    table::{Align, Table},
+    unlock,
+};
+
+#[derive(Clone, Debug, serde::Deserialize)]
+pub struct Leaderboard {
+    pub event: String,
+    pub members: BTreeMap<String, Member>,
+}
+
+#[derive(Clone, Debug, serde::Deserialize)]
+pub struct Member {
+    pub id: u64,
+    pub name: Option<String>,
+    pub stars: u32,
+    /// As computed by the site, see [`Leaderboard::standings`]
+    pub local_score: u32,
+    pub last_star_ts: u64,
+    /// Day to part to star, both keyed by their number
+    #[serde(default)]
+    pub completion_day_level: BTreeMap<String, BTreeMap<String, Star>>,
+}
+
+#[derive(Clone, Copy, Debug, serde::Deserialize)]
+pub struct Star {
+    pub get_star_ts: u64,
+    /// Order in which stars were collected across the whole leaderboard
+    pub star_index: u64,
+}
+
+impl Member {
+    pub fn name(&self) -> String {
+        match &self.name {
+            Some(name) => name.clone(),
+            None => format!("(anonymous user #{})", self.id),
+        }
+    }
+
+    pub fn star(&self, day: u8, part: Part) -> Option<&Star> {
+        self.completion_day_level
+            .get(&day.to_string())?
+            .get(&part.number().to_string())
+    }
+}
+
+/// A member's place on the leaderboard
+#[derive(Clone, Debug)]
+pub struct Standing<'a> {
+    pub member: &'a Member,
+    pub score: u32,
+}
+
+impl Leaderboard {
+    pub fn parse(json: &str) -> eyre::Result<Self> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    pub fn year(&self) -> eyre::Result<u16> {
+        self.event
+            .parse()
+            .map_err(|_| eyre::format_err!("unexpected event {:?}", self.event))
+    }
+
+    /// Every member by local score: each star is worth one point per member, minus one for
+    /// each member who got it earlier. Ties go to whoever got their last star first.
+    pub fn standings(&self) -> Vec<Standing<'_>> {
+        let members = self.members.len() as u32;
+        let mut scores: BTreeMap<u64, u32> = self.members.values().map(|m| (m.id, 0)).collect();
+
+        for day in 1..=25 {
+            for part in Part::ALL {
+                let mut stars: Vec<(&Star, u64)> = self
+                    .members
+                    .values()
+                    .filter_map(|m| m.star(day, part).map(|star| (star, m.id)))
+                    .collect();
+                stars.sort_by_key(|(star, _)| (star.get_star_ts, star.star_index));
+
+                for (rank, (_, id)) in stars.into_iter().enumerate() {
+                    *scores.entry(id).or_default() += members - rank as u32;
+                }
+            }
+        }
+
+        let mut standings: Vec<_> = self
+            .members
+            .values()
+            .map(|member| Standing {
+                member,
+                score: scores[&member.id],
+            })
+            .collect();
+        standings.sort_by_key(|s| {
+            (
+                std::cmp::Reverse(s.score),
+                std::cmp::Reverse(s.member.stars),
+                s.member.last_star_ts,
+            )
+        });
+        standings
+    }
+
+    /// Time from the puzzle's unlock to the star
+    pub fn elapsed(&self, member: &Member, day: u8, part: Part) -> Option<Duration> {
+        let unlock = unlock::unlock_time(self.year().ok()?, day)?;
+        let star = std::time::UNIX_EPOCH + Duration::from_secs(member.star(day, part)?.get_star_ts);
+        star.duration_since(unlock).ok()
+    }
+
+    /// Days that anyone has a star for
+    fn days(&self) -> u8 {
+        (1..=25)
+            .rev()
+            .find(|&day| {
+                self.members
+                    .values()
+                    .any(|m| m.star(day, Part::One).is_some())
+            })
+            .unwrap_or_default()
+    }
+
+    /// Standings with one column per day: `*` for both stars, `+` for only the first
+    pub fn table(&self) -> Table {
+        let mut table = Table::new(&[
+            ("#", Align::Right),
+            ("member", Align::Left),
+            ("score", Align::Right),
+            ("stars", Align::Right),
+            ("days", Align::Left),
+        ]);
+
+        for (place, standing) in self.standings().iter().enumerate() {
+            let member = standing.member;
+            let days = (1..=self.days())
+                .map(
+                    |day| match (member.star(day, Part::One), member.star(day, Part::Two)) {
+                        (_, Some(_)) => '*',
+                        (Some(_), None) => '+',
+                        (None, None) => '.',
+                    },
+                )
+                .collect();
+            table.row(vec![
+                (place + 1).to_string(),
+                member.name(),
+                standing.score.to_string(),
+                member.stars.to_string(),
+                days,
+            ]);
+        }
+
+        table
+    }
+
+    /// When each member got the stars of one day, counted from its unlock
+    pub fn day_table(&self, day: u8) -> Table {
+        let mut table = Table::new(&[
+            ("member", Align::Left),
+            ("part 1", Align::Right),
+            ("part 2", Align::Right),
+            ("gap", Align::Right),
+        ]);
+
+        let mut members: Vec<&Member> = self
+            .members
+            .values()
+            .filter(|m| m.star(day, Part::One).is_some())
+            .collect();
+        members.sort_by_key(|m| {
+            let ts = |part| m.star(day, part).map_or(u64::MAX, |s| s.get_star_ts);
+            (ts(Part::Two), ts(Part::One))
+        });
+
+        let format = |d: Option<Duration>| d.map(unlock::countdown).unwrap_or_default();
+        for member in members {
+            let part1 = self.elapsed(member, day, Part::One);
+            let part2 = self.elapsed(member, day, Part::Two);
+            let gap = part1
+                .zip(part2)
+                .map(|(part1, part2)| part2.saturating_sub(part1));
+            table.row(vec![
+                member.name(),
+                format(part1),
+                format(part2),
+                format(gap),
+            ]);
+        }
+
+        table
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::Leaderboard;
+    use crate::Part;
+
+    fn leaderboard() -> Leaderboard {
+        Leaderboard::parse(include_str!("../fixtures/leaderboard-2015-123456.json")).unwrap()
+    }
+
+    #[test]
+    fn parse() {
+        let leaderboard = leaderboard();
+        assert_eq!(leaderboard.year().unwrap(), 2015);
+        assert_eq!(leaderboard.members.len(), 4);
+        assert_eq!(leaderboard.members["3"].name(), "(anonymous user #3)");
+
+        let alice = &leaderboard.members["1"];
+        assert_eq!(
+            leaderboard.elapsed(alice, 1, Part::Two),
+            Some(Duration::from_secs(1200))
+        );
+        assert_eq!(leaderboard.elapsed(alice, 3, Part::One), None);
+    }
+
+    #[test]
+    fn local_scores_match_the_site() {
+        let leaderboard = leaderboard();
+        let standings = leaderboard.standings();
+
+        let names: Vec<_> = standings.iter().map(|s| s.member.name()).collect();
+        assert_eq!(names, ["Alice", "Bob", "(anonymous user #3)", "Dave"]);
+        for standing in standings {
+            assert_eq!(standing.score, standing.member.local_score);
+        }
+    }
+
+    #[test]
+    fn tables() {
+        let leaderboard = leaderboard();
+
+        assert_eq!(
+            leaderboard.table().to_string(),
+            "\
+#  member               score  stars  days
+------------------------------------------
+1  Alice                   15      4  **
+2  Bob                      7      2  *.
+3  (anonymous user #3)      5      2  ++
+4  Dave                     0      0  ..
+"
+        );
+
+        let day = leaderboard.day_table(1).to_string();
+        let rows: Vec<_> = day.lines().skip(2).collect();
+        assert_eq!(
+            rows,
+            [
+                "Bob                  00:10:00  00:15:00  00:05:00",
+                "Alice                00:05:00  00:20:00  00:15:00",
+                "(anonymous user #3)  00:16:40",
+            ]
+        );
+    }
+}
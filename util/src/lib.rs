@@ -105,4 +105,20 @@ pub mod parse {
     }
 }
 
+pub mod answers;
+pub mod client;
+pub mod examples;
 pub mod grid;
+pub mod input;
+pub mod leaderboard;
+pub mod mock;
+pub mod profile;
+pub mod puzzle;
+pub mod server;
+pub mod solution;
+pub mod store;
+pub mod submit;
+pub mod table;
+pub mod unlock;
+
+pub use solution::{Part, Solution};
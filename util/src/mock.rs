@@ -0,0 +1,227 @@
+//! A stand-in for adventofcode.com that serves fixture files, so the [client](crate::client)
+//! can be tested without a network. Everything is looked up in one directory:
+//!
+//! - `<year>-<day>-input.txt`, days without one are not unlocked yet
+//! - `<year>-<day>-part1.html`, and `<year>-<day>-part2.html` once part 1 is solved
+//! - `<year>-<day>-answers.toml`, the [`Answers`] submissions are checked against
+//! - `answer-correct.html`, `answer-too-high.html`, `answer-too-low.html`,
+//!   `answer-wrong.html` and `answer-wrong-level.html`, the responses to submissions
+//! - `input-logged-out.txt` and `input-locked.txt`, the site's error pages
+//! - `leaderboard-<year>-<id>.json`, private leaderboards
+
+use std::{
+    collections::HashSet,
+    path::{Path, PathBuf},
+    sync::Mutex,
+};
+
+use eyre::WrapErr;
+
+use crate::{Part, answers::Answers, server::Server, store};
+
+/// Serves fixtures on a background thread until dropped
+pub struct MockServer(Server);
+
+impl MockServer {
+    /// Listens on a free port of localhost, only requests with `session` are logged in
+    pub fn start(fixtures: impl Into<PathBuf>, session: impl Into<String>) -> eyre::Result<Self> {
+        Self::bind("127.0.0.1:0", fixtures, session)
+    }
+
+    pub fn bind(
+        addr: &str,
+        fixtures: impl Into<PathBuf>,
+        session: impl Into<String>,
+    ) -> eyre::Result<Self> {
+        let site = Site {
+            fixtures: fixtures.into(),
+            session: session.into(),
+            solved: Mutex::default(),
+        };
+        Ok(Self(Server::bind(addr, move |mut request| {
+            let response = site.respond(&mut request);
+            let _ = request.respond(response);
+        })?))
+    }
+
+    /// Base URL to point the client at
+    pub fn url(&self) -> &str {
+        self.0.url()
+    }
+
+    /// Serves until the process is killed
+    pub fn wait(self) {
+        self.0.wait()
+    }
+}
+
+type Response = tiny_http::Response<std::io::Cursor<Vec<u8>>>;
+
+struct Site {
+    fixtures: PathBuf,
+    session: String,
+    /// (year, day, part) answered correctly since the server started
+    solved: Mutex<HashSet<(u16, u8, u8)>>,
+}
+
+impl Site {
+    fn respond(&self, request: &mut tiny_http::Request) -> Response {
+        let logged_in = request
+            .headers()
+            .iter()
+            .filter(|h| h.field.equiv("Cookie"))
+            .flat_map(|h| h.value.as_str().split(';'))
+            .any(|cookie| cookie.trim().strip_prefix("session=") == Some(&self.session));
+        let post = *request.method() == tiny_http::Method::Post;
+        let mut body = String::new();
+        let _ = request.as_reader().read_to_string(&mut body);
+
+        let url = request.url().to_string();
+        let segments: Vec<&str> = url.trim_matches('/').split('/').collect();
+        let result = match segments[..] {
+            [year, "day", day, "input"] if !post => {
+                route(year, day, |year, day| self.input(logged_in, year, day))
+            }
+            [year, "day", day] if !post => {
+                route(year, day, |year, day| self.puzzle(logged_in, year, day))
+            }
+            [year, "day", day, "answer"] if post => route(year, day, |year, day| {
+                self.answer(logged_in, year, day, &body)
+            }),
+            [year, "leaderboard", "private", "view", id] if !post => {
+                self.leaderboard(logged_in, year, id)
+            }
+            _ => Ok(not_found()),
+        };
+
+        result.unwrap_or_else(|e| {
+            tiny_http::Response::from_string(format!("{e:#}")).with_status_code(500)
+        })
+    }
+
+    fn fixture(&self, name: &str) -> eyre::Result<String> {
+        let path = self.fixtures.join(name);
+        std::fs::read_to_string(&path)
+            .wrap_err_with(|| format!("missing fixture {}", path.display()))
+    }
+
+    fn page(&self, name: &str, status: u16) -> eyre::Result<Response> {
+        Ok(tiny_http::Response::from_string(self.fixture(name)?).with_status_code(status))
+    }
+
+    fn unlocked(&self, year: u16, day: u8) -> bool {
+        self.fixtures
+            .join(format!("{year}-{day:02}-input.txt"))
+            .is_file()
+    }
+
+    fn input(&self, logged_in: bool, year: u16, day: u8) -> eyre::Result<Response> {
+        match (logged_in, self.unlocked(year, day)) {
+            (false, _) => self.page("input-logged-out.txt", 400),
+            (true, false) => self.page("input-locked.txt", 404),
+            (true, true) => self.page(&format!("{year}-{day:02}-input.txt"), 200),
+        }
+    }
+
+    fn puzzle(&self, logged_in: bool, year: u16, day: u8) -> eyre::Result<Response> {
+        if !self.unlocked(year, day) {
+            return Ok(not_found());
+        }
+        if !logged_in {
+            return self.page(&format!("{year}-{day:02}-logged-out.html"), 200);
+        }
+
+        let part2 = format!("{year}-{day:02}-part2.html");
+        if self.is_solved(year, day, Part::One) && self.fixtures.join(&part2).is_file() {
+            self.page(&part2, 200)
+        } else {
+            self.page(&format!("{year}-{day:02}-part1.html"), 200)
+        }
+    }
+
+    fn is_solved(&self, year: u16, day: u8, part: Part) -> bool {
+        let solved = self
+            .solved
+            .lock()
+            .expect("no handler panics with the lock held");
+        solved.contains(&(year, day, part.number()))
+    }
+
+    fn answer(&self, logged_in: bool, year: u16, day: u8, body: &str) -> eyre::Result<Response> {
+        if !logged_in {
+            return self.page("input-logged-out.txt", 400);
+        }
+        if !self.unlocked(year, day) {
+            return Ok(not_found());
+        }
+
+        let field = |name| {
+            form_urlencoded::parse(body.as_bytes())
+                .find(|(key, _)| key == name)
+                .map(|(_, value)| value.into_owned())
+                .unwrap_or_default()
+        };
+        let answer = field("answer");
+        let part = match field("level").as_str() {
+            "1" => Part::One,
+            "2" => Part::Two,
+            _ => return self.page("answer-wrong-level.html", 200),
+        };
+        if self.is_solved(year, day, part)
+            || (part == Part::Two && !self.is_solved(year, day, Part::One))
+        {
+            return self.page("answer-wrong-level.html", 200);
+        }
+
+        let answers: Answers =
+            store::load_or_default(&self.fixtures.join(format!("{year}-{day:02}-answers.toml")))?;
+        let expected = answers.get(part).unwrap_or_default();
+        let page = if answer == expected {
+            let mut solved = self
+                .solved
+                .lock()
+                .expect("no handler panics with the lock held");
+            solved.insert((year, day, part.number()));
+            "answer-correct.html"
+        } else {
+            match (answer.parse::<i128>(), expected.parse::<i128>()) {
+                (Ok(answer), Ok(expected)) if answer > expected => "answer-too-high.html",
+                (Ok(answer), Ok(expected)) if answer < expected => "answer-too-low.html",
+                _ => "answer-wrong.html",
+            }
+        };
+        self.page(page, 200)
+    }
+
+    fn leaderboard(&self, logged_in: bool, year: &str, id: &str) -> eyre::Result<Response> {
+        if !logged_in {
+            return Ok(
+                tiny_http::Response::from_string("You don't seem to be logged in.")
+                    .with_status_code(400),
+            );
+        }
+        let name = format!("leaderboard-{year}-{id}");
+        match Path::new(&name).extension() == Some("json".as_ref())
+            && self.fixtures.join(&name).is_file()
+        {
+            true => self.page(&name, 200),
+            false => Ok(not_found()),
+        }
+    }
+}
+
+/// Parses the year and day of a URL before handling it
+fn route(
+    year: &str,
+    day: &str,
+    handle: impl FnOnce(u16, u8) -> eyre::Result<Response>,
+) -> eyre::Result<Response> {
+    match (year.parse(), day.parse()) {
+        (Ok(year), Ok(day)) => handle(year, day),
+        _ => Ok(not_found()),
+    }
+}
+
+fn not_found() -> Response {
+    tiny_http::Response::from_string("404 Not Found").with_status_code(404)
+}
@@ -0,0 +1,152 @@
+//! Named accounts, each with its own session and inputs, from a config file like
+//!
+//! ```toml
+//! default = "alice"
+//!
+//! [profiles.alice]
+//! session = "53616c7465645f5f..."
+//!
+//! [profiles.bob]
+//! session = "53616c7465645f5f..."
+//! inputs = "/home/bob/aoc-inputs"
+//! ```
+
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+};
+
+use crate::{client::Client, input::Locator, store};
+
+/// Environment variable overriding the location of the config file
+pub const CONFIG_ENV: &str = "AOC_CONFIG";
+
+/// Environment variable selecting a profile
+pub const PROFILE_ENV: &str = "AOC_PROFILE";
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, serde::Deserialize)]
+pub struct Config {
+    /// Profile used when none is selected
+    pub default: Option<String>,
+    #[serde(default)]
+    pub profiles: BTreeMap<String, Profile>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, serde::Deserialize)]
+pub struct Profile {
+    #[serde(skip)]
+    pub name: String,
+    pub session: String,
+    /// Inputs laid out as `<year>/<day>.txt`, with their answers and attempts beside them.
+    /// Relative to the config file, `inputs/<name>` next to it by default.
+    pub inputs: Option<PathBuf>,
+}
+
+impl Config {
+    /// `$AOC_CONFIG`, or `aoc/config.toml` in the user's config directory
+    pub fn path() -> Option<PathBuf> {
+        store::user_path(CONFIG_ENV, "XDG_CONFIG_HOME", ".config", "aoc/config.toml")
+    }
+
+    /// A missing file has no profiles
+    pub fn load(path: &Path) -> eyre::Result<Self> {
+        let config: Self = store::load_or_default(path)?;
+        Ok(config.resolve(path.parent().unwrap_or(Path::new("."))))
+    }
+
+    pub fn from_env() -> eyre::Result<Self> {
+        match Self::path() {
+            Some(path) => Self::load(&path),
+            None => Ok(Self::default()),
+        }
+    }
+
+    /// Resolves the inputs of every profile against `dir`
+    fn resolve(mut self, dir: &Path) -> Self {
+        for (name, profile) in &mut self.profiles {
+            profile.name = name.clone();
+            let inputs = profile
+                .inputs
+                .take()
+                .unwrap_or_else(|| Path::new("inputs").join(name));
+            profile.inputs = Some(dir.join(inputs));
+        }
+        self
+    }
+
+    pub fn profile(&self, name: &str) -> eyre::Result<&Profile> {
+        self.profiles.get(name).ok_or_else(|| {
+            eyre::format_err!(
+                "no profile named {name}, the configured ones are: {}",
+                self.profiles.keys().cloned().collect::<Vec<_>>().join(", ")
+            )
+        })
+    }
+
+    /// The named profile, else `$AOC_PROFILE`, else the default one, if any
+    pub fn select(&self, name: Option<&str>) -> eyre::Result<Option<&Profile>> {
+        let env = std::env::var(PROFILE_ENV).ok().filter(|v| !v.is_empty());
+        match name.or(env.as_deref()).or(self.default.as_deref()) {
+            Some(name) => self.profile(name).map(Some),
+            None => Ok(None),
+        }
+    }
+}
+
+impl Profile {
+    pub fn inputs(&self) -> &Path {
+        self.inputs
+            .as_deref()
+            .expect("inputs are resolved when the config is loaded")
+    }
+
+    pub fn input_path(&self, year: u16, day: u8) -> PathBuf {
+        self.inputs()
+            .join(year.to_string())
+            .join(format!("{day:02}.txt"))
+    }
+
+    /// Finds inputs only in the profile's own directory, never another account's `input.txt`
+    pub fn locator(&self) -> Locator {
+        Locator {
+            path: None,
+            inputs_dir: Some(self.inputs().to_path_buf()),
+            inputs_only: true,
+        }
+    }
+
+    pub fn client(&self) -> Client {
+        Client::new(&self.session)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::Config;
+
+    #[test]
+    fn parse() -> eyre::Result<()> {
+        let config: Config = toml::from_str(
+            "default = \"alice\"\n\n[profiles.alice]\nsession = \"a\"\n\n[profiles.bob]\nsession = \"b\"\ninputs = \"/elsewhere\"\n",
+        )?;
+        let config = config.resolve(Path::new("/config"));
+
+        let alice = config.profile("alice")?;
+        assert_eq!(alice.name, "alice");
+        assert_eq!(alice.session, "a");
+        assert_eq!(
+            alice.input_path(2015, 7),
+            Path::new("/config/inputs/alice/2015/07.txt")
+        );
+        assert_eq!(config.profile("bob")?.inputs(), Path::new("/elsewhere"));
+
+        assert_eq!(
+            config.select(Some("bob"))?.map(|p| p.session.as_str()),
+            Some("b")
+        );
+        assert!(config.select(Some("carol")).is_err());
+        Ok(())
+    }
+}
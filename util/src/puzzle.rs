@@ -0,0 +1,278 @@
+//! Scraping of the puzzle page, `https://adventofcode.com/<year>/day/<day>`
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// Text of every `<pre><code>` block on the page, in order
+pub fn examples(html: &str) -> Vec<String> {
+    let mut examples = Vec::new();
+    let mut rest = html;
+
+    while let Some(start) = rest.find("<pre><code>") {
+        rest = &rest[start + "<pre><code>".len()..];
+        let end = rest.find("</code></pre>").unwrap_or(rest.len());
+        examples.push(unescape(&strip_tags(&rest[..end])));
+        rest = &rest[end..];
+    }
+
+    examples
+}
+
+/// Every `<article class="day-desc">` of the page as Markdown, part 1 and, once it is
+/// unlocked, part 2
+pub fn statement(html: &str) -> Vec<String> {
+    const START: &str = "<article class=\"day-desc\">";
+
+    let mut articles = Vec::new();
+    let mut rest = html;
+
+    while let Some(start) = rest.find(START) {
+        rest = &rest[start + START.len()..];
+        let end = rest.find("</article>").unwrap_or(rest.len());
+        articles.push(markdown(&rest[..end]));
+        rest = &rest[end..];
+    }
+
+    articles
+}
+
+/// Adds the articles `existing` does not contain yet, so a re-fetch after part 1 only
+/// appends part 2. `None` when there is nothing new.
+pub fn append_statement(existing: &str, articles: &[String]) -> Option<String> {
+    let new: Vec<&str> = articles
+        .iter()
+        .map(|a| a.trim())
+        .filter(|a| !existing.contains(a))
+        .collect();
+    if new.is_empty() {
+        return None;
+    }
+
+    let mut parts = vec![existing.trim()];
+    parts.extend(new);
+    Some(parts.join("\n\n").trim_start().to_string() + "\n")
+}
+
+/// Converts the little HTML the puzzle descriptions use
+fn markdown(html: &str) -> String {
+    let mut md = String::new();
+    let (mut pre, mut code) = (false, false);
+    let mut links = Vec::new();
+    let mut rest = html;
+
+    while !rest.is_empty() {
+        let Some(start) = rest.find('<') else {
+            push_text(&mut md, rest, pre);
+            break;
+        };
+        push_text(&mut md, &rest[..start], pre);
+
+        let end = rest[start..]
+            .find('>')
+            .map_or(rest.len(), |end| start + end);
+        let tag = &rest[start + 1..end];
+        rest = rest.get(end + 1..).unwrap_or_default();
+
+        let (name, attributes) = tag.split_once(' ').unwrap_or((tag, ""));
+        match name {
+            "h2" => md.push_str("## "),
+            "/h2" | "/p" | "/ul" => md.push_str("\n\n"),
+            "li" => md.push_str("- "),
+            "/li" => md.push('\n'),
+            "pre" => {
+                pre = true;
+                md.push_str("```\n");
+            }
+            "/pre" => {
+                pre = false;
+                if !md.ends_with('\n') {
+                    md.push('\n');
+                }
+                md.push_str("```\n\n");
+            }
+            "code" if !pre => {
+                code = true;
+                md.push('`');
+            }
+            "/code" if !pre => {
+                code = false;
+                md.push('`');
+            }
+            "em" | "/em" if !pre && !code => md.push_str("**"),
+            "a" => {
+                links.push(
+                    attribute(attributes, "href")
+                        .unwrap_or_default()
+                        .to_string(),
+                );
+                md.push('[');
+            }
+            "/a" => md.push_str(&format!("]({})", links.pop().unwrap_or_default())),
+            _ => {}
+        }
+    }
+
+    let lines: Vec<&str> = md.lines().map(str::trim_end).collect();
+    let mut md = lines.join("\n");
+    while md.contains("\n\n\n") {
+        md = md.replace("\n\n\n", "\n\n");
+    }
+    md.trim().to_string() + "\n"
+}
+
+/// Text between tags, keeping line breaks only inside `<pre>`
+fn push_text(md: &mut String, text: &str, pre: bool) {
+    if pre {
+        md.push_str(&unescape(text));
+    } else if !(text.trim().is_empty() && text.contains('\n')) {
+        md.push_str(&unescape(&text.replace('\n', " ")));
+    }
+}
+
+fn attribute<'a>(attributes: &'a str, name: &str) -> Option<&'a str> {
+    let start = attributes.find(&format!("{name}=\""))? + name.len() + 2;
+    let len = attributes[start..].find('"')?;
+    Some(&attributes[start..start + len])
+}
+
+/// Drops the markup, like the `<em>` highlighting of examples
+pub(crate) fn strip_tags(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut rest = html;
+
+    while let Some(start) = rest.find('<') {
+        text.push_str(&rest[..start]);
+        rest = match rest[start..].find('>') {
+            Some(end) => &rest[start + end + 1..],
+            None => "",
+        };
+    }
+    text.push_str(rest);
+
+    text
+}
+
+/// Decodes the entities the site uses
+pub fn unescape(html: &str) -> String {
+    html.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+/// The first `lines` lines of an example, each cut to `width` characters
+pub fn preview(example: &str, lines: usize, width: usize) -> String {
+    let mut preview: Vec<String> = example
+        .lines()
+        .take(lines)
+        .map(|line| match line.char_indices().nth(width) {
+            Some((i, _)) => format!("{}…", &line[..i]),
+            None => line.to_string(),
+        })
+        .collect();
+    if example.lines().count() > lines {
+        preview.push("…".into());
+    }
+
+    preview.join("\n")
+}
+
+/// Saves examples as `<dir>/01.txt`, `<dir>/02.txt`, ..., replacing earlier downloads; any other
+/// numbered files are removed, the page may have fewer examples than last time
+pub fn write_examples(dir: &Path, examples: &[String]) -> eyre::Result<Vec<PathBuf>> {
+    fs::create_dir_all(dir)?;
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        let numbered = path
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .is_some_and(|stem| !stem.is_empty() && stem.bytes().all(|b| b.is_ascii_digit()));
+        if numbered && path.extension() == Some("txt".as_ref()) {
+            fs::remove_file(&path)?;
+        }
+    }
+
+    examples
+        .iter()
+        .enumerate()
+        .map(|(i, example)| {
+            let path = dir.join(format!("{:02}.txt", i + 1));
+            fs::write(&path, example)?;
+            Ok(path)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{append_statement, examples, preview, statement, write_examples};
+
+    const PAGE: &str = include_str!("../fixtures/2015-07-part1.html");
+    const PAGE_PART2: &str = include_str!("../fixtures/2015-07-part2.html");
+
+    #[test]
+    fn rewrites_examples() -> eyre::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let dir = dir.path();
+        std::fs::write(dir.join("larger.txt"), "by hand")?;
+        write_examples(dir, &["a".into(), "b".into(), "c".into()])?;
+        write_examples(dir, &["d".into(), "e".into()])?;
+
+        let mut names: Vec<_> = std::fs::read_dir(dir)?
+            .map(|entry| Ok(entry?.file_name().into_string().unwrap()))
+            .collect::<eyre::Result<_>>()?;
+        names.sort();
+        assert_eq!(names, ["01.txt", "02.txt", "larger.txt"]);
+        assert_eq!(std::fs::read_to_string(dir.join("02.txt"))?, "e");
+        Ok(())
+    }
+
+    #[test]
+    fn extracts_examples() {
+        let examples = examples(PAGE);
+
+        assert_eq!(examples.len(), 2);
+        assert!(examples[0].starts_with("123 -> x\n456 -> y\nx AND y -> d\n"));
+        assert!(examples[0].ends_with("NOT y -> i\n"));
+        assert!(examples[1].starts_with("d: 72\ne: 507\n"));
+    }
+
+    #[test]
+    fn previews_are_short() {
+        assert_eq!(
+            preview("123 -> x\n456 -> y\nx AND y -> d\n", 2, 6),
+            "123 ->…\n456 ->…\n…"
+        );
+        assert_eq!(preview("1\n2\n", 2, 6), "1\n2");
+    }
+
+    #[test]
+    fn converts_statement_to_markdown() {
+        let articles = statement(PAGE);
+        assert_eq!(articles.len(), 1);
+
+        let md = &articles[0];
+        assert!(md.starts_with("## --- Day 7: Some Assembly Required ---\n\nA kit of **wires** and [bitwise logic gates](https://en.wikipedia.org/wiki/Logic_gate) has arrived."));
+        assert!(md.contains("\n\n- `123 -> x` means that the signal `123` is provided to wire `x`.\n- `x AND y -> z`"));
+        assert!(md.contains("\n\n```\n123 -> x\n456 -> y\n"));
+        assert!(md.contains("NOT y -> i\n```\n\nAfter it is run"));
+        assert!(md.contains("```\nd: 72\n"));
+        assert!(md.ends_with("**what signal is ultimately provided to wire `a`?**\n"));
+        assert!(!md.contains('<'));
+    }
+
+    #[test]
+    fn refetch_appends_part2() {
+        let part1 = append_statement("", &statement(PAGE)).unwrap();
+        assert_eq!(part1, statement(PAGE)[0]);
+        assert_eq!(append_statement(&part1, &statement(PAGE)), None);
+
+        let both = append_statement(&part1, &statement(PAGE_PART2)).unwrap();
+        assert!(both.starts_with(&part1));
+        assert!(both.ends_with("\n\n## --- Part Two ---\n\nNow, take the signal you got on wire `a`, override wire `b` to that signal, and reset the other wires (including wire `a`).  **What new signal is ultimately provided to wire `a`?**\n"));
+        assert_eq!(append_statement(&both, &statement(PAGE_PART2)), None);
+    }
+}
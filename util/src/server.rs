@@ -0,0 +1,62 @@
+use std::{sync::Arc, thread::JoinHandle};
+
+pub use tiny_http;
+
+/// A small HTTP server on localhost, handling requests on a background thread until dropped
+pub struct Server {
+    server: Arc<tiny_http::Server>,
+    thread: Option<JoinHandle<()>>,
+    url: String,
+}
+
+impl Server {
+    /// Hands every request to `handle` in turn, which responds to it
+    pub fn bind(
+        addr: &str,
+        mut handle: impl FnMut(tiny_http::Request) + Send + 'static,
+    ) -> eyre::Result<Self> {
+        let server = tiny_http::Server::http(addr)
+            .map_err(|e| eyre::format_err!("failed to listen on {addr}: {e}"))?;
+        let addr = server
+            .server_addr()
+            .to_ip()
+            .expect("the server listens on an IP address");
+        let server = Arc::new(server);
+
+        let thread = std::thread::spawn({
+            let server = server.clone();
+            move || {
+                for request in server.incoming_requests() {
+                    handle(request);
+                }
+            }
+        });
+
+        Ok(Self {
+            server,
+            thread: Some(thread),
+            url: format!("http://{addr}"),
+        })
+    }
+
+    /// Base URL of the server, with the port it ended up on
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// Serves until the process is killed
+    pub fn wait(mut self) {
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+impl Drop for Server {
+    fn drop(&mut self) {
+        self.server.unblock();
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
@@ -0,0 +1,333 @@
+use std::{fmt::Display, str::FromStr};
+
+pub use eyre;
+pub use inventory;
+
+/// Implemented by every day crate and submitted to the registry with [`crate::register!`]
+pub trait Solution {
+    const YEAR: u16;
+    const DAY: u8;
+    const TITLE: &'static str;
+    /// Parts that only return a placeholder answer so far
+    const STUBS: &'static [Part] = &[];
+
+    /// The puzzle input once parsed, shared by both parts; it may borrow from the text
+    type Input<'a>;
+    /// Constants the puzzle text changes between the examples and the real input, `()` if none
+    type Params: Params;
+
+    fn parse(input: &str) -> eyre::Result<Self::Input<'_>>;
+    fn part1(input: &Self::Input<'_>, params: &Self::Params) -> eyre::Result<impl Display>;
+    fn part2(input: &Self::Input<'_>, params: &Self::Params) -> eyre::Result<impl Display>;
+}
+
+/// Named values a part depends on besides its input, declared with [`crate::params!`];
+/// `Default` gives the values for the real input
+pub trait Params: Default {
+    const NAMES: &'static [&'static str];
+
+    /// Overrides the value of `name` from text, as given on the command line
+    fn set(&mut self, name: &str, value: &str) -> eyre::Result<()>;
+}
+
+impl Params for () {
+    const NAMES: &'static [&'static str] = &[];
+
+    fn set(&mut self, name: &str, _value: &str) -> eyre::Result<()> {
+        eyre::bail!("unknown parameter {name:?}, this puzzle has none")
+    }
+}
+
+/// Parses the text of a parameter, for the `set` generated by [`crate::params!`]
+pub fn parse_param<T>(name: &str, value: &str) -> eyre::Result<T>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    value
+        .parse()
+        .map_err(|e| eyre::format_err!("invalid value {value:?} for parameter {name:?}: {e}"))
+}
+
+/// Declares the [`Params`] of a day, with the value each takes for the real input:
+///
+/// ```ignore
+/// aoc_util::params! {
+///     pub struct Params {
+///         /// How many steps the lights are animated for
+///         steps: usize = 100,
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! params {
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident {
+            $($(#[$field_meta:meta])* $field:ident: $ty:ty = $default:expr),* $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Clone, Debug, PartialEq)]
+        $vis struct $name {
+            $($(#[$field_meta])* pub $field: $ty,)*
+        }
+
+        impl Default for $name {
+            fn default() -> Self {
+                Self {
+                    $($field: $default,)*
+                }
+            }
+        }
+
+        impl $crate::solution::Params for $name {
+            const NAMES: &'static [&'static str] = &[$(stringify!($field)),*];
+
+            fn set(&mut self, name: &str, value: &str) -> $crate::solution::eyre::Result<()> {
+                match name {
+                    $(stringify!($field) => self.$field = $crate::solution::parse_param(name, value)?,)*
+                    _ => $crate::solution::eyre::bail!(
+                        "unknown parameter {name:?}, expected one of {:?}",
+                        <Self as $crate::solution::Params>::NAMES
+                    ),
+                }
+                Ok(())
+            }
+        }
+    };
+}
+
+/// A `name=value` override of one of a puzzle's [`Params`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Param {
+    pub name: String,
+    pub value: String,
+}
+
+impl FromStr for Param {
+    type Err = eyre::Report;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (name, value) = s
+            .split_once('=')
+            .ok_or_else(|| eyre::format_err!("parameter must be name=value, got {s:?}"))?;
+        Ok(Param {
+            name: name.trim().to_string(),
+            value: value.trim().to_string(),
+        })
+    }
+}
+
+impl Display for Param {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}={}", self.name, self.value)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+impl FromStr for Part {
+    type Err = eyre::Report;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(eyre::format_err!("part must be 1 or 2, got {s:?}")),
+        }
+    }
+}
+
+/// A type-erased [`Solution`], as stored in the registry
+#[derive(Debug)]
+pub struct Puzzle {
+    pub year: u16,
+    pub day: u8,
+    pub title: &'static str,
+    /// Cargo package the solution was registered from, e.g. `aoc2024day06`
+    pub package: &'static str,
+    /// Directory of the package, where `input.txt` lives
+    pub manifest_dir: &'static str,
+    pub stubs: &'static [Part],
+    /// Names of the solution's [`Params`], which can be overridden when parsing
+    pub params: &'static [&'static str],
+    parse: ParseFn,
+}
+
+type ParseFn = for<'a> fn(&'a str, &[Param]) -> eyre::Result<Box<dyn Parsed + 'a>>;
+
+/// A parsed input of a type-erased [`Solution`], ready to solve either part
+pub trait Parsed {
+    fn solve(&self, part: Part) -> eyre::Result<String>;
+}
+
+struct Input<'a, S: Solution>(S::Input<'a>, S::Params);
+
+impl<S: Solution> Parsed for Input<'_, S> {
+    fn solve(&self, part: Part) -> eyre::Result<String> {
+        match part {
+            Part::One => S::part1(&self.0, &self.1).map(|answer| answer.to_string()),
+            Part::Two => S::part2(&self.0, &self.1).map(|answer| answer.to_string()),
+        }
+    }
+}
+
+fn parse<'a, S: Solution + 'static>(
+    input: &'a str,
+    overrides: &[Param],
+) -> eyre::Result<Box<dyn Parsed + 'a>> {
+    let mut params = S::Params::default();
+    for param in overrides {
+        params.set(&param.name, &param.value)?;
+    }
+    Ok(Box::new(Input::<S>(S::parse(input)?, params)))
+}
+
+impl Puzzle {
+    pub const fn new<S: Solution + 'static>(package: &'static str, manifest_dir: &'static str) -> Self {
+        Self {
+            year: S::YEAR,
+            day: S::DAY,
+            title: S::TITLE,
+            package,
+            manifest_dir,
+            stubs: S::STUBS,
+            params: S::Params::NAMES,
+            parse: parse::<S>,
+        }
+    }
+
+    /// Parses the input once, to solve any number of parts with; `params` override the defaults
+    /// of the solution's [`Params`]
+    pub fn parse<'a>(
+        &self,
+        input: &'a str,
+        params: &[Param],
+    ) -> eyre::Result<Box<dyn Parsed + 'a>> {
+        (self.parse)(input, params)
+    }
+
+    pub fn solve(&self, part: Part, input: &str) -> eyre::Result<String> {
+        self.parse(input, &[])?.solve(part)
+    }
+}
+
+impl Display for Puzzle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}/{:02}: {}", self.year, self.day, self.title)
+    }
+}
+
+inventory::collect!(Puzzle);
+
+/// Every registered puzzle linked into the current binary, ordered by year and day
+pub fn all() -> Vec<&'static Puzzle> {
+    let mut puzzles: Vec<_> = inventory::iter::<Puzzle>().collect();
+    puzzles.sort_by_key(|p| (p.year, p.day));
+    puzzles
+}
+
+pub fn find(year: u16, day: u8) -> Option<&'static Puzzle> {
+    inventory::iter::<Puzzle>().find(|p| p.year == year && p.day == day)
+}
+
+/// Submits a [`Solution`] implementor to the registry, e.g. `aoc_util::register!(Day);`
+#[macro_export]
+macro_rules! register {
+    ($solution:ty) => {
+        $crate::solution::inventory::submit! {
+            $crate::solution::Puzzle::new::<$solution>(
+                env!("CARGO_PKG_NAME"),
+                env!("CARGO_MANIFEST_DIR"),
+            )
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Param, Part, Solution};
+
+    struct Example;
+    impl Solution for Example {
+        const YEAR: u16 = 1999;
+        const DAY: u8 = 1;
+        const TITLE: &'static str = "Example";
+
+        type Input<'a> = Vec<&'a str>;
+        type Params = Params;
+
+        fn parse(input: &str) -> eyre::Result<Self::Input<'_>> {
+            Ok(input.split(',').collect())
+        }
+        fn part1(input: &Self::Input<'_>, params: &Params) -> eyre::Result<impl std::fmt::Display> {
+            Ok(input.len() * params.times)
+        }
+        fn part2(input: &Self::Input<'_>, _: &Params) -> eyre::Result<impl std::fmt::Display> {
+            Ok(input.concat().to_uppercase())
+        }
+    }
+    crate::register!(Example);
+
+    crate::params! {
+        struct Params {
+            times: usize = 1,
+        }
+    }
+
+    #[test]
+    fn registered() -> eyre::Result<()> {
+        let puzzle = super::find(1999, 1).expect("example is registered");
+        assert_eq!(puzzle.title, "Example");
+        assert_eq!(puzzle.package, "aoc-util");
+        assert_eq!(puzzle.solve(Part::One, "a,b,c")?, "3");
+        let parsed = puzzle.parse("a,b,c", &[])?;
+        assert_eq!(parsed.solve(Part::One)?, "3");
+        assert_eq!(parsed.solve(Part::Two)?, "ABC");
+        assert!(super::all().iter().any(|p| p.year == 1999));
+        assert!(puzzle.stubs.is_empty());
+        assert_eq!(puzzle.params, ["times"]);
+        Ok(())
+    }
+
+    #[test]
+    fn params_override_defaults() -> eyre::Result<()> {
+        let puzzle = super::find(1999, 1).expect("example is registered");
+        let times: Param = "times = 4".parse()?;
+        assert_eq!(puzzle.parse("a,b", &[times])?.solve(Part::One)?, "8");
+
+        let unknown: Param = "steps=4".parse()?;
+        assert!(puzzle.parse("a,b", &[unknown]).is_err());
+        let invalid: Param = "times=many".parse()?;
+        assert!(puzzle.parse("a,b", &[invalid]).is_err());
+        assert!("times".parse::<Param>().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn part_from_str() {
+        assert_eq!("1".parse::<Part>().unwrap(), Part::One);
+        assert_eq!("2".parse::<Part>().unwrap(), Part::Two);
+        assert!("3".parse::<Part>().is_err());
+    }
+}
@@ -0,0 +1,73 @@
+//! Where the files of answers, attempts, profiles and baselines are kept, and how they are read
+//! and written
+
+use std::path::{Path, PathBuf};
+
+use eyre::WrapErr;
+
+/// Reads a TOML file, a missing one is the default value
+pub fn load_or_default<T: serde::de::DeserializeOwned + Default>(path: &Path) -> eyre::Result<T> {
+    match std::fs::read_to_string(path) {
+        Ok(content) => {
+            toml::from_str(&content).wrap_err_with(|| format!("failed to parse {}", path.display()))
+        }
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(T::default()),
+        Err(e) => Err(e).wrap_err_with(|| format!("failed to read {}", path.display())),
+    }
+}
+
+/// Writes a TOML file, creating its directory
+pub fn save<T: serde::Serialize>(path: &Path, value: &T) -> eyre::Result<()> {
+    if let Some(dir) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+        std::fs::create_dir_all(dir)?;
+    }
+    std::fs::write(path, toml::to_string(value)?)
+        .wrap_err_with(|| format!("failed to write {}", path.display()))
+}
+
+/// A file kept beside an input: `<kind>.toml` beside a day's `input.txt`, `<name>.<kind>`
+/// beside any other `<name>.txt`
+pub fn sidecar(input: &Path, kind: &str) -> PathBuf {
+    if input.file_name().is_some_and(|name| name == "input.txt") {
+        input.with_file_name(format!("{kind}.toml"))
+    } else {
+        input.with_extension(kind)
+    }
+}
+
+/// `$<var>`, else `path` in `$<xdg>`, else `path` in `home` of the home directory
+pub fn user_path(var: &str, xdg: &str, home: &str, path: &str) -> Option<PathBuf> {
+    let env = |name: &str| {
+        std::env::var_os(name)
+            .filter(|v| !v.is_empty())
+            .map(PathBuf::from)
+    };
+
+    env(var)
+        .or_else(|| env(xdg).map(|dir| dir.join(path)))
+        .or_else(|| env("HOME").map(|dir| dir.join(home).join(path)))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::{load_or_default, save};
+    use crate::answers::Answers;
+
+    #[test]
+    fn missing_files_are_default() -> eyre::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("nested/01.answers");
+        assert_eq!(load_or_default::<Answers>(&path)?, Answers::default());
+
+        let answers = Answers {
+            part1: Some("7".into()),
+            ..Default::default()
+        };
+        save(&path, &answers)?;
+        assert_eq!(load_or_default::<Answers>(&path)?, answers);
+        assert!(load_or_default::<Answers>(Path::new("/")).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,277 @@
+//! Answer submission: what the site said, and what we already tried
+
+use std::{
+    fmt::Display,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime},
+};
+
+use crate::{Part, puzzle, store};
+
+/// The site's verdict on a submitted answer
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Response {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint which way
+    Wrong,
+    /// Too soon after the previous attempt, nothing was checked
+    RateLimited {
+        #[serde(with = "seconds")]
+        wait: Duration,
+    },
+    /// The part is already solved, or not unlocked yet
+    WrongLevel,
+}
+
+impl Response {
+    /// Reads the `<article>` of the page the answer endpoint responds with
+    pub fn parse(html: &str) -> eyre::Result<Self> {
+        let text = match (html.find("<article>"), html.find("</article>")) {
+            (Some(start), Some(end)) if start < end => &html[start..end],
+            _ => html,
+        };
+        let text = puzzle::unescape(&puzzle::strip_tags(text));
+
+        Ok(if text.contains("That's the right answer") {
+            Response::Correct
+        } else if text.contains("your answer is too high") {
+            Response::TooHigh
+        } else if text.contains("your answer is too low") {
+            Response::TooLow
+        } else if text.contains("That's not the right answer") {
+            Response::Wrong
+        } else if text.contains("You gave an answer too recently") {
+            Response::RateLimited {
+                wait: parse_wait(&text).unwrap_or_default(),
+            }
+        } else if text.contains("You don't seem to be solving the right level") {
+            Response::WrongLevel
+        } else {
+            eyre::bail!("unrecognized response: {}", text.trim())
+        })
+    }
+
+    /// Whether the answer was checked and turned out wrong
+    pub fn is_wrong(self) -> bool {
+        matches!(self, Response::TooHigh | Response::TooLow | Response::Wrong)
+    }
+}
+
+impl Display for Response {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Response::Correct => write!(f, "correct"),
+            Response::TooHigh => write!(f, "wrong, too high"),
+            Response::TooLow => write!(f, "wrong, too low"),
+            Response::Wrong => write!(f, "wrong"),
+            Response::RateLimited { wait } => {
+                write!(f, "rate limited, wait {}s", wait.as_secs())
+            }
+            Response::WrongLevel => write!(f, "not the current part, already solved or locked"),
+        }
+    }
+}
+
+/// "You have 4m 51s left to wait."
+fn parse_wait(text: &str) -> Option<Duration> {
+    let start = text.find("You have ")? + "You have ".len();
+    let end = start + text[start..].find(" left to wait")?;
+
+    text[start..end]
+        .split_whitespace()
+        .map(|amount| {
+            let unit = amount.trim_start_matches(|c: char| c.is_ascii_digit());
+            let n: u64 = amount[..amount.len() - unit.len()].parse().ok()?;
+            match unit {
+                "h" => Some(n * 3600),
+                "m" => Some(n * 60),
+                "s" => Some(n),
+                _ => None,
+            }
+        })
+        .sum::<Option<u64>>()
+        .map(Duration::from_secs)
+}
+
+mod seconds {
+    use std::time::Duration;
+
+    pub fn serialize<S: serde::Serializer>(d: &Duration, s: S) -> Result<S::Ok, S::Error> {
+        s.serialize_u64(d.as_secs())
+    }
+
+    pub fn deserialize<'de, D: serde::Deserializer<'de>>(d: D) -> Result<Duration, D::Error> {
+        serde::Deserialize::deserialize(d).map(Duration::from_secs)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct Attempt {
+    pub part: u8,
+    pub answer: String,
+    pub response: Response,
+    /// Seconds since the Unix epoch
+    pub at: u64,
+}
+
+/// Every answer submitted for one input, stored as TOML next to it like
+/// [`Answers`](crate::answers::Answers)
+#[derive(Clone, Debug, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct Attempts {
+    #[serde(default, rename = "attempt")]
+    pub attempts: Vec<Attempt>,
+}
+
+impl Attempts {
+    /// `attempts.toml` beside a day's `input.txt`, `<name>.attempts` beside any other
+    /// `<name>.txt`
+    pub fn sidecar(input: &Path) -> PathBuf {
+        store::sidecar(input, "attempts")
+    }
+
+    pub fn record(&mut self, part: Part, answer: &str, response: Response) {
+        let at = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        self.attempts.push(Attempt {
+            part: part.number(),
+            answer: answer.to_string(),
+            response,
+            at,
+        });
+    }
+
+    fn of(&self, part: Part) -> impl Iterator<Item = &Attempt> {
+        self.attempts
+            .iter()
+            .filter(move |a| a.part == part.number())
+    }
+
+    /// Why submitting `answer` would be pointless, judging by earlier attempts
+    pub fn refusal(&self, part: Part, answer: &str) -> Option<String> {
+        if let Some(correct) = self.of(part).find(|a| a.response == Response::Correct) {
+            return Some(format!(
+                "part {part} was already solved with {}",
+                correct.answer
+            ));
+        }
+        if let Some(wrong) = self
+            .of(part)
+            .find(|a| a.answer == answer && a.response.is_wrong())
+        {
+            return Some(format!(
+                "{answer} was already submitted: {}",
+                wrong.response
+            ));
+        }
+
+        let answer: i128 = answer.parse().ok()?;
+        let bound = |response| {
+            self.of(part)
+                .filter(move |a| a.response == response)
+                .filter_map(|a| a.answer.parse::<i128>().ok())
+        };
+        if let Some(high) = bound(Response::TooHigh)
+            .min()
+            .filter(|&high| answer >= high)
+        {
+            return Some(format!("{answer} is not below {high}, which was too high"));
+        }
+        if let Some(low) = bound(Response::TooLow).max().filter(|&low| answer <= low) {
+            return Some(format!("{answer} is not above {low}, which was too low"));
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{Attempts, Response};
+    use crate::Part;
+
+    #[test]
+    fn parse_responses() -> eyre::Result<()> {
+        let parse = |html| Response::parse(html);
+
+        assert_eq!(
+            parse(include_str!("../fixtures/answer-correct.html"))?,
+            Response::Correct
+        );
+        assert_eq!(
+            parse(include_str!("../fixtures/answer-too-high.html"))?,
+            Response::TooHigh
+        );
+        assert_eq!(
+            parse(include_str!("../fixtures/answer-too-low.html"))?,
+            Response::TooLow
+        );
+        assert_eq!(
+            parse(include_str!("../fixtures/answer-wrong.html"))?,
+            Response::Wrong
+        );
+        assert_eq!(
+            parse(include_str!("../fixtures/answer-rate-limited.html"))?,
+            Response::RateLimited {
+                wait: Duration::from_secs(4 * 60 + 51)
+            }
+        );
+        assert_eq!(
+            parse(include_str!("../fixtures/answer-wrong-level.html"))?,
+            Response::WrongLevel
+        );
+        assert!(parse("<article><p>Something else</p></article>").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn refuses_pointless_answers() {
+        let mut attempts = Attempts::default();
+        attempts.record(Part::One, "500", Response::TooHigh);
+        attempts.record(Part::One, "100", Response::TooLow);
+        attempts.record(Part::One, "300", Response::Wrong);
+        attempts.record(
+            Part::One,
+            "400",
+            Response::RateLimited {
+                wait: Duration::ZERO,
+            },
+        );
+
+        assert!(attempts.refusal(Part::One, "300").is_some());
+        assert!(attempts.refusal(Part::One, "500").is_some());
+        assert!(attempts.refusal(Part::One, "501").is_some());
+        assert!(attempts.refusal(Part::One, "100").is_some());
+        assert!(attempts.refusal(Part::One, "42").is_some());
+        assert_eq!(attempts.refusal(Part::One, "400"), None);
+        assert_eq!(attempts.refusal(Part::One, "299"), None);
+        assert_eq!(attempts.refusal(Part::Two, "500"), None);
+
+        attempts.record(Part::One, "299", Response::Correct);
+        assert!(attempts.refusal(Part::One, "299").is_some());
+    }
+
+    #[test]
+    fn round_trip() -> eyre::Result<()> {
+        let mut attempts = Attempts::default();
+        attempts.record(
+            Part::Two,
+            "17",
+            Response::RateLimited {
+                wait: Duration::from_secs(30),
+            },
+        );
+        attempts.record(Part::Two, "18", Response::TooLow);
+
+        let s = toml::to_string(&attempts)?;
+        assert!(s.starts_with("[[attempt]]\npart = 2\nanswer = \"17\"\n"));
+        assert_eq!(toml::from_str::<Attempts>(&s)?, attempts);
+        Ok(())
+    }
+}
@@ -0,0 +1,118 @@
+use std::fmt::Display;
+
+#[derive(Clone, Copy, Debug)]
+pub enum Align {
+    Left,
+    Right,
+}
+
+#[derive(Debug)]
+enum Row {
+    Cells(Vec<String>),
+    Separator,
+}
+
+/// Plain text table, columns are sized to fit their widest cell
+#[derive(Debug)]
+pub struct Table {
+    columns: Vec<(&'static str, Align)>,
+    rows: Vec<Row>,
+}
+
+impl Table {
+    pub fn new(columns: &[(&'static str, Align)]) -> Self {
+        Self {
+            columns: columns.to_vec(),
+            rows: vec![],
+        }
+    }
+
+    /// Missing trailing cells are left blank
+    pub fn row(&mut self, cells: Vec<String>) {
+        self.rows.push(Row::Cells(cells));
+    }
+
+    pub fn separator(&mut self) {
+        self.rows.push(Row::Separator);
+    }
+
+    fn widths(&self) -> Vec<usize> {
+        self.columns
+            .iter()
+            .enumerate()
+            .map(|(i, (header, _))| {
+                self.rows
+                    .iter()
+                    .filter_map(|row| match row {
+                        Row::Cells(cells) => cells.get(i),
+                        Row::Separator => None,
+                    })
+                    .map(|cell| cell.chars().count())
+                    .chain([header.len()])
+                    .max()
+                    .unwrap_or_default()
+            })
+            .collect()
+    }
+}
+
+impl Display for Table {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let widths = self.widths();
+
+        let write_cells = |f: &mut std::fmt::Formatter<'_>, cells: &[&str]| {
+            let line = self
+                .columns
+                .iter()
+                .zip(&widths)
+                .enumerate()
+                .map(|(i, ((_, align), &width))| {
+                    let cell = cells.get(i).copied().unwrap_or_default();
+                    match align {
+                        Align::Left => format!("{cell:<width$}"),
+                        Align::Right => format!("{cell:>width$}"),
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join("  ");
+            writeln!(f, "{}", line.trim_end())
+        };
+
+        let headers: Vec<_> = self.columns.iter().map(|(header, _)| *header).collect();
+        write_cells(f, &headers)?;
+
+        let total = widths.iter().sum::<usize>() + 2 * widths.len().saturating_sub(1);
+        let separator = "-".repeat(total);
+        writeln!(f, "{separator}")?;
+
+        for row in &self.rows {
+            match row {
+                Row::Cells(cells) => {
+                    let cells: Vec<_> = cells.iter().map(String::as_str).collect();
+                    write_cells(f, &cells)?;
+                }
+                Row::Separator => writeln!(f, "{separator}")?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Align, Table};
+
+    #[test]
+    fn aligns_columns() {
+        let mut table = Table::new(&[("name", Align::Left), ("n", Align::Right)]);
+        table.row(vec!["a".into(), "100".into()]);
+        table.separator();
+        table.row(vec!["total".into()]);
+
+        assert_eq!(
+            table.to_string(),
+            "name     n\n----------\na      100\n----------\ntotal\n"
+        );
+    }
+}
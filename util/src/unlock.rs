@@ -0,0 +1,115 @@
+//! When puzzles unlock: midnight US Eastern, which in December is always 05:00 UTC
+
+use std::{
+    thread,
+    time::{Duration, Instant, SystemTime},
+};
+
+use crate::client;
+
+/// How long to keep asking for a puzzle the site still reports as locked, our clock and
+/// theirs are never quite in sync
+pub const RETRY_WINDOW: Duration = Duration::from_secs(30);
+
+pub const RETRY_INTERVAL: Duration = Duration::from_secs(2);
+
+/// The first event
+pub const FIRST_YEAR: u16 = 2015;
+
+/// Days of an event
+pub const DAYS: std::ops::RangeInclusive<u8> = 1..=25;
+
+/// Days since 1970-01-01 of a proleptic Gregorian date
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146097 + day_of_era - 719468
+}
+
+/// `None` for a day that has no puzzle
+pub fn unlock_time(year: u16, day: u8) -> Option<SystemTime> {
+    if year < FIRST_YEAR || !DAYS.contains(&day) {
+        return None;
+    }
+    let days = days_from_civil(year.into(), 12, day.into());
+    let seconds = days * 86400 + 5 * 3600;
+    Some(SystemTime::UNIX_EPOCH + Duration::from_secs(seconds.try_into().ok()?))
+}
+
+/// Time left until the puzzle unlocks, `None` once it has or for a day that has no puzzle
+pub fn remaining(year: u16, day: u8, now: SystemTime) -> Option<Duration> {
+    unlock_time(year, day)?
+        .duration_since(now)
+        .ok()
+        .filter(|d| !d.is_zero())
+}
+
+/// `2d 03:04:05`, or `03:04:05` within a day
+pub fn countdown(d: Duration) -> String {
+    let s = d.as_secs();
+    let clock = format!("{:02}:{:02}:{:02}", s / 3600 % 24, s / 60 % 60, s % 60);
+    match s / 86400 {
+        0 => clock,
+        days => format!("{days}d {clock}"),
+    }
+}
+
+/// Repeats `fetch` while the site still says the puzzle is locked, within [`RETRY_WINDOW`]
+pub fn retry<T>(mut fetch: impl FnMut() -> eyre::Result<T>) -> eyre::Result<T> {
+    let start = Instant::now();
+    loop {
+        match fetch() {
+            Err(e)
+                if e.downcast_ref() == Some(&client::Error::Locked)
+                    && start.elapsed() + RETRY_INTERVAL < RETRY_WINDOW =>
+            {
+                thread::sleep(RETRY_INTERVAL)
+            }
+            result => return result,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, SystemTime};
+
+    use super::{countdown, remaining, unlock_time};
+
+    fn at(seconds: u64) -> SystemTime {
+        SystemTime::UNIX_EPOCH + Duration::from_secs(seconds)
+    }
+
+    #[test]
+    fn unlocks_at_midnight_eastern() {
+        // 2015-12-01T05:00:00Z and 2024-12-25T05:00:00Z
+        assert_eq!(unlock_time(2015, 1), Some(at(1448946000)));
+        assert_eq!(unlock_time(2024, 25), Some(at(1735102800)));
+        assert_eq!(unlock_time(1969, 1), None);
+        assert_eq!(unlock_time(2024, 0), None);
+        assert_eq!(unlock_time(2024, 26), None);
+    }
+
+    #[test]
+    fn time_remaining() {
+        let unlock = 1735102800;
+        assert_eq!(
+            remaining(2024, 25, at(unlock - 3723)),
+            Some(Duration::from_secs(3723))
+        );
+        assert_eq!(remaining(2024, 25, at(unlock)), None);
+        assert_eq!(remaining(2024, 25, at(unlock + 1)), None);
+    }
+
+    #[test]
+    fn countdowns() {
+        assert_eq!(countdown(Duration::from_secs(3723)), "01:02:03");
+        assert_eq!(
+            countdown(Duration::from_secs(2 * 86400 + 59)),
+            "2d 00:00:59"
+        );
+    }
+}
@@ -0,0 +1,194 @@
+//! Runs getinput against the mock server, end to end and without a network
+
+use std::{
+    path::Path,
+    process::{Command, Output},
+};
+
+use aoc_util::{
+    client::{self, Client},
+    mock::MockServer,
+    puzzle,
+};
+
+const SESSION: &str = "mock-session";
+
+fn fixtures() -> &'static Path {
+    Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/fixtures"))
+}
+
+/// getinput with nothing from the environment but the server and a cache in `dir`
+fn getinput(url: &str, dir: &Path, session: &str, args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_getinput"))
+        .args(args)
+        .args(["--base-url", url, "--session", session])
+        .env("AOC_CACHE", dir.join("cache"))
+        .env("AOC_INPUTS", dir.join("inputs"))
+        .env("AOC_CONFIG", dir.join("config.toml"))
+        .env_remove("AOC_PROFILE")
+        .output()
+        .unwrap()
+}
+
+fn stdout(output: &Output) -> String {
+    assert!(output.status.success(), "{output:?}");
+    String::from_utf8_lossy(&output.stdout).into_owned()
+}
+
+fn stderr(output: &Output) -> String {
+    assert!(!output.status.success(), "{output:?}");
+    String::from_utf8_lossy(&output.stderr).into_owned()
+}
+
+#[test]
+fn fetch_input() {
+    let server = MockServer::start(fixtures(), SESSION).unwrap();
+    let dir = tempfile::tempdir().unwrap();
+    let dir = dir.path();
+    let input = std::fs::read_to_string(fixtures().join("2015-07-input.txt")).unwrap();
+
+    let url = server.url();
+    assert_eq!(stdout(&getinput(url, dir, SESSION, &["2015", "7"])), input);
+
+    let expired = getinput(url, dir, "expired", &["2015", "7"]);
+    assert!(stderr(&expired).contains("not logged in"));
+    let locked = getinput(url, dir, SESSION, &["2015", "8"]);
+    assert!(stderr(&locked).contains("not unlocked"));
+    let future = getinput(url, dir, SESSION, &["2999", "1"]);
+    assert!(stderr(&future).contains("pass --wait"));
+    let before = getinput(url, dir, SESSION, &["1969", "1"]);
+    assert!(stderr(&before).contains("1969"));
+    let after = getinput(url, dir, SESSION, &["2015", "26"]);
+    assert!(stderr(&after).contains("26"));
+
+    // cached, the site is not asked again unless forced
+    let url = url.to_string();
+    drop(server);
+    assert_eq!(stdout(&getinput(&url, dir, SESSION, &["2015", "7"])), input);
+    assert!(
+        !getinput(&url, dir, SESSION, &["2015", "7", "--force"])
+            .status
+            .success()
+    );
+
+    // the same session on the real site never gets the mock's input
+    let mut mock = Client::new(SESSION).with_base_uri(&url);
+    mock.cache = Some(dir.join("cache"));
+    let site = mock.clone().with_base_uri(client::BASE_URI);
+    assert!(mock.cached_input(2015, 7).unwrap().is_file());
+    assert!(!site.cached_input(2015, 7).unwrap().exists());
+}
+
+#[test]
+fn submit_answers() {
+    let server = MockServer::start(fixtures(), SESSION).unwrap();
+    let dir = tempfile::tempdir().unwrap();
+    let dir = dir.path();
+    std::fs::create_dir_all(dir.join("inputs/2015")).unwrap();
+    std::fs::copy(
+        fixtures().join("2015-07-input.txt"),
+        dir.join("inputs/2015/07.txt"),
+    )
+    .unwrap();
+    let submit = |part, answer| {
+        getinput(
+            server.url(),
+            dir,
+            SESSION,
+            &["submit", "2015", "7", part, answer],
+        )
+    };
+
+    assert!(stdout(&submit("1", "100")).contains("too high"));
+    assert!(stderr(&submit("1", "150")).contains("too high"));
+    assert!(stdout(&submit("1", "50")).contains("too low"));
+    assert!(stdout(&submit("1", "abc")).contains("wrong"));
+    assert!(stderr(&submit("1", "abc")).contains("already submitted"));
+
+    let client = Client::new(SESSION).with_base_uri(server.url());
+    assert_eq!(puzzle::statement(&client.puzzle(2015, 7).unwrap()).len(), 1);
+
+    assert!(stdout(&submit("1", "72")).contains("correct"));
+    assert!(stderr(&submit("1", "72")).contains("already solved"));
+    assert_eq!(puzzle::statement(&client.puzzle(2015, 7).unwrap()).len(), 2);
+
+    let answers = std::fs::read_to_string(dir.join("inputs/2015/07.answers")).unwrap();
+    assert_eq!(answers, "part1 = \"72\"\n");
+    let attempts = std::fs::read_to_string(dir.join("inputs/2015/07.attempts")).unwrap();
+    assert_eq!(attempts.matches("[[attempt]]").count(), 4);
+}
+
+#[test]
+fn leaderboard() {
+    let server = MockServer::start(fixtures(), SESSION).unwrap();
+    let dir = tempfile::tempdir().unwrap();
+    let dir = dir.path();
+
+    let url = server.url().to_string();
+    let standings = stdout(&getinput(
+        &url,
+        dir,
+        SESSION,
+        &["leaderboard", "2015", "123456"],
+    ));
+    assert!(standings.starts_with("#  member"), "{standings}");
+    assert!(standings.contains("1  Alice"), "{standings}");
+
+    // cached, the API is not polled again within the quarter hour
+    drop(server);
+    let day = stdout(&getinput(
+        &url,
+        dir,
+        SESSION,
+        &["leaderboard", "2015", "123456", "--day", "1"],
+    ));
+    assert!(day.contains("Bob"), "{day}");
+}
+
+#[test]
+fn logged_out() {
+    let server = MockServer::start(fixtures(), SESSION).unwrap();
+    let client = Client::new("expired").with_base_uri(server.url());
+
+    let err = client.puzzle(2015, 7).unwrap_err();
+    assert!(err.to_string().contains("not logged in"), "{err}");
+    let err = client
+        .submit(2015, 7, aoc_util::Part::One, "72")
+        .unwrap_err();
+    assert!(err.to_string().contains("not logged in"), "{err}");
+}
+
+#[test]
+fn profile_wins_over_session_env() {
+    let server = MockServer::start(fixtures(), SESSION).unwrap();
+    let dir = tempfile::tempdir().unwrap();
+    let dir = dir.path();
+    let config = format!(
+        "[profiles.bob]\nsession = \"{SESSION}\"\ninputs = {:?}\n",
+        dir.join("bob")
+    );
+    std::fs::write(dir.join("config.toml"), config).unwrap();
+    let input = std::fs::read_to_string(fixtures().join("2015-07-input.txt")).unwrap();
+
+    // the session of the environment is expired, the profile's is not
+    let getinput = |profile_env: Option<&str>, args: &[&str]| {
+        let mut command = Command::new(env!("CARGO_BIN_EXE_getinput"));
+        command
+            .args(args)
+            .args(["--base-url", server.url()])
+            .env(aoc_util::client::SESSION_ENV, "expired")
+            .env("AOC_CACHE", dir.join("cache"))
+            .env("AOC_CONFIG", dir.join("config.toml"))
+            .env_remove("AOC_PROFILE");
+        if let Some(profile) = profile_env {
+            command.env("AOC_PROFILE", profile);
+        }
+        command.output().unwrap()
+    };
+
+    assert_eq!(stdout(&getinput(None, &["-P", "bob", "2015", "7"])), input);
+    assert_eq!(
+        stdout(&getinput(Some("bob"), &["2015", "7", "--force"])),
+        input
+    );
+}
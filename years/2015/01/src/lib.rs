@@ -1,18 +1,23 @@
-fn next_floor(floor: isize, input: char) -> isize {
-    match input {
-        '(' => floor + 1,
-        ')' => floor - 1,
-        _ => floor,
-    }
+/// One floor up for every `(`, one down for every `)`
+pub fn parse(input: &str) -> eyre::Result<Vec<isize>> {
+    input
+        .trim_end()
+        .chars()
+        .map(|c| match c {
+            '(' => Ok(1),
+            ')' => Ok(-1),
+            _ => Err(eyre::format_err!("unexpected {c:?}")),
+        })
+        .collect()
 }
 
-pub fn part1(input: &str) -> eyre::Result<isize> {
-    Ok(input.chars().fold(0isize, next_floor))
+pub fn part1(steps: &[isize]) -> eyre::Result<isize> {
+    Ok(steps.iter().sum())
 }
-pub fn part2(input: &str) -> eyre::Result<usize> {
+pub fn part2(steps: &[isize]) -> eyre::Result<usize> {
     let mut floor = 0;
-    for (position, input) in input.chars().enumerate() {
-        floor = next_floor(floor, input);
+    for (position, step) in steps.iter().enumerate() {
+        floor += step;
         if floor < 0 {
             return Ok(position + 1);
         }
@@ -21,18 +26,29 @@ pub fn part2(input: &str) -> eyre::Result<usize> {
     eyre::bail!("never entered basement");
 }
 
-#[cfg(test)]
-mod tests {
-    const INPUT: &str = "))(((((";
+pub struct Day;
+
+impl aoc_util::Solution for Day {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 1;
+    const TITLE: &'static str = "Not Quite Lisp";
+
+    type Input<'a> = Vec<isize>;
+    type Params = ();
 
-    #[test]
-    fn part1_works() -> eyre::Result<()> {
-        assert_eq!(super::part1(INPUT)?, 3);
-        Ok(())
+    fn parse(input: &str) -> eyre::Result<Self::Input<'_>> {
+        parse(input)
     }
-    #[test]
-    fn part2_works() -> eyre::Result<()> {
-        assert_eq!(super::part2(INPUT)?, 1);
-        Ok(())
+    fn part1(input: &Self::Input<'_>, _: &Self::Params) -> eyre::Result<impl std::fmt::Display> {
+        part1(input)
     }
+    fn part2(input: &Self::Input<'_>, _: &Self::Params) -> eyre::Result<impl std::fmt::Display> {
+        part2(input)
+    }
+}
+aoc_util::register!(Day);
+
+aoc_util::examples! {
+    Day;
+    example: "))(((((" => (3, 1),
 }
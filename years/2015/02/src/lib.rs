@@ -1,7 +1,7 @@
 use itertools::Itertools;
 
 #[derive(Debug, derive_more::Deref)]
-struct Dimensions([u32; 3]);
+pub struct Dimensions([u32; 3]);
 impl Dimensions {
     fn new(mut lengths: [u32; 3]) -> Self {
         lengths.sort();
@@ -16,45 +16,59 @@ impl Dimensions {
     fn volume(&self) -> u32 {
         self.0.iter().product()
     }
-    fn parse(input: &str) -> Self {
+    fn parse(input: &str) -> eyre::Result<Self> {
         let sizes = input
             .split("x")
-            .filter_map(|s| s.parse::<u32>().ok())
-            .collect_vec();
+            .map(|s| s.parse::<u32>())
+            .collect::<Result<Vec<_>, _>>()?;
+        let sizes = sizes
+            .try_into()
+            .map_err(|_| eyre::format_err!("expected three sizes: {input}"))?;
 
-        assert!(sizes.len() == 3);
-
-        Dimensions::new(sizes.try_into().unwrap())
+        Ok(Dimensions::new(sizes))
     }
 }
 
-pub fn part1(input: &str) -> eyre::Result<u32> {
-    Ok(input
-        .lines()
-        .map(Dimensions::parse)
+pub fn parse(input: &str) -> eyre::Result<Vec<Dimensions>> {
+    input.lines().map(Dimensions::parse).collect()
+}
+
+pub fn part1(presents: &[Dimensions]) -> eyre::Result<u32> {
+    Ok(presents
+        .iter()
         .map(|p| p.sides().map(|s| 2 * s).sum::<u32>() + p.sides().min().unwrap())
         .sum())
 }
-pub fn part2(input: &str) -> eyre::Result<u32> {
-    Ok(input
-        .lines()
-        .map(Dimensions::parse)
+pub fn part2(presents: &[Dimensions]) -> eyre::Result<u32> {
+    Ok(presents
+        .iter()
         .map(|p| p.perimeters().min().unwrap() + p.volume())
         .sum())
 }
 
-#[cfg(test)]
-mod tests {
-    const INPUT: &str = "2x3x4";
+pub struct Day;
+
+impl aoc_util::Solution for Day {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 2;
+    const TITLE: &'static str = "I Was Told There Would Be No Math";
 
-    #[test]
-    fn part1_works() -> eyre::Result<()> {
-        assert_eq!(super::part1(INPUT)?, 58);
-        Ok(())
+    type Input<'a> = Vec<Dimensions>;
+    type Params = ();
+
+    fn parse(input: &str) -> eyre::Result<Self::Input<'_>> {
+        parse(input)
+    }
+    fn part1(input: &Self::Input<'_>, _: &Self::Params) -> eyre::Result<impl std::fmt::Display> {
+        part1(input)
     }
-    #[test]
-    fn part2_works() -> eyre::Result<()> {
-        assert_eq!(super::part2(INPUT)?, 34);
-        Ok(())
+    fn part2(input: &Self::Input<'_>, _: &Self::Params) -> eyre::Result<impl std::fmt::Display> {
+        part2(input)
     }
 }
+aoc_util::register!(Day);
+
+aoc_util::examples! {
+    Day;
+    example: "2x3x4" => (58, 34),
+}
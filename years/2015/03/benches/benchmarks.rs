@@ -1,14 +1,28 @@
-const INPUT: &str = include_str!("../input.txt");
+use std::sync::LazyLock;
+
+use aoc_util::Solution;
+use aoc2015day03::Day;
+
+static INPUT: LazyLock<String> = LazyLock::new(|| {
+    aoc_util::input::load::<Day>(env!("CARGO_MANIFEST_DIR")).expect("input is available")
+});
+static PARAMS: LazyLock<<Day as Solution>::Params> = LazyLock::new(Default::default);
 
 fn main() {
     divan::main()
 }
 
 #[divan::bench]
-fn part1() {
-    aoc2015day03::part1(INPUT).unwrap();
+fn parse() -> impl Sized {
+    Day::parse(divan::black_box(&INPUT)).unwrap()
+}
+#[divan::bench]
+fn part1(bencher: divan::Bencher) {
+    let input = Day::parse(&INPUT).unwrap();
+    bencher.bench(|| Day::part1(&input, &PARAMS).unwrap());
 }
 #[divan::bench]
-fn part2() {
-    aoc2015day03::part2(INPUT).unwrap();
+fn part2(bencher: divan::Bencher) {
+    let input = Day::parse(&INPUT).unwrap();
+    bencher.bench(|| Day::part2(&input, &PARAMS).unwrap());
 }
@@ -1,17 +1,20 @@
 use glam::I64Vec2;
 use std::collections::HashSet;
 
-fn directions(input: &str) -> impl Iterator<Item = I64Vec2> + use<'_> {
-    input.chars().map(|c| match c {
-        '^' => I64Vec2::Y,
-        '>' => I64Vec2::X,
-        'v' => I64Vec2::NEG_Y,
-        '<' => I64Vec2::NEG_X,
-        _ => I64Vec2::ZERO,
-    })
+pub fn parse(input: &str) -> eyre::Result<Vec<I64Vec2>> {
+    Ok(input
+        .chars()
+        .map(|c| match c {
+            '^' => I64Vec2::Y,
+            '>' => I64Vec2::X,
+            'v' => I64Vec2::NEG_Y,
+            '<' => I64Vec2::NEG_X,
+            _ => I64Vec2::ZERO,
+        })
+        .collect())
 }
 
-fn visited(directions: impl Iterator<Item = I64Vec2>) -> HashSet<I64Vec2> {
+fn visited<'a>(directions: impl Iterator<Item = &'a I64Vec2>) -> HashSet<I64Vec2> {
     let mut pos = I64Vec2::new(0, 0);
     let mut seen = HashSet::new();
     for dir in directions {
@@ -21,32 +24,43 @@ fn visited(directions: impl Iterator<Item = I64Vec2>) -> HashSet<I64Vec2> {
     seen
 }
 
-pub fn part1(input: &str) -> eyre::Result<u32> {
-    let seen = visited(directions(input));
+pub fn part1(directions: &[I64Vec2]) -> eyre::Result<u32> {
+    let seen = visited(directions.iter());
 
     Ok(seen.len() as u32)
 }
-pub fn part2(input: &str) -> eyre::Result<u32> {
-    let santa_directions = directions(input).step_by(2);
+pub fn part2(directions: &[I64Vec2]) -> eyre::Result<u32> {
+    let santa_directions = directions.iter().step_by(2);
     let santa_seen = visited(santa_directions);
-    let robot_directions = directions(input).skip(1).step_by(2);
+    let robot_directions = directions.iter().skip(1).step_by(2);
     let robo_seen = visited(robot_directions);
 
     Ok(santa_seen.union(&robo_seen).count() as u32)
 }
 
-#[cfg(test)]
-mod tests {
-    const INPUT: &str = "^>v<";
+pub struct Day;
 
-    #[test]
-    fn part1_works() -> eyre::Result<()> {
-        assert_eq!(super::part1(INPUT)?, 4);
-        Ok(())
+impl aoc_util::Solution for Day {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 3;
+    const TITLE: &'static str = "Perfectly Spherical Houses in a Vacuum";
+
+    type Input<'a> = Vec<I64Vec2>;
+    type Params = ();
+
+    fn parse(input: &str) -> eyre::Result<Self::Input<'_>> {
+        parse(input)
+    }
+    fn part1(input: &Self::Input<'_>, _: &Self::Params) -> eyre::Result<impl std::fmt::Display> {
+        part1(input)
     }
-    #[test]
-    fn part2_works() -> eyre::Result<()> {
-        assert_eq!(super::part2(INPUT)?, 3);
-        Ok(())
+    fn part2(input: &Self::Input<'_>, _: &Self::Params) -> eyre::Result<impl std::fmt::Display> {
+        part2(input)
     }
 }
+aoc_util::register!(Day);
+
+aoc_util::examples! {
+    Day;
+    example: "^>v<" => (4, 3),
+}
@@ -35,10 +35,7 @@ pub fn search(input: &str, prefix: &str) -> eyre::Result<u32> {
                 format!("{s:x}").starts_with(&prefix)
             });
 
-            match attempt {
-                Some(value) => *found.lock().unwrap() = Some(value),
-                None => {}
-            }
+            if let Some(value) = attempt { *found.lock().unwrap() = Some(value) }
         }))
     }
 
@@ -50,25 +47,41 @@ pub fn search(input: &str, prefix: &str) -> eyre::Result<u32> {
     found.ok_or(eyre::format_err!("wtf"))
 }
 
-pub fn part1(input: &str) -> eyre::Result<u32> {
-    search(input, "00000")
+/// The secret key
+pub fn parse(input: &str) -> eyre::Result<&str> {
+    Ok(input.trim())
+}
+
+pub fn part1(key: &str) -> eyre::Result<u32> {
+    search(key, "00000")
 }
-pub fn part2(input: &str) -> eyre::Result<u32> {
-    search(input, "000000")
+pub fn part2(key: &str) -> eyre::Result<u32> {
+    search(key, "000000")
 }
 
-#[cfg(test)]
-mod tests {
-    const INPUT: &str = "abcdef";
+pub struct Day;
+
+impl aoc_util::Solution for Day {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 4;
+    const TITLE: &'static str = "The Ideal Stocking Stuffer";
 
-    #[test]
-    fn part1_works() -> eyre::Result<()> {
-        assert_eq!(super::part1(INPUT)?, 609043);
-        Ok(())
+    type Input<'a> = &'a str;
+    type Params = ();
+
+    fn parse(input: &str) -> eyre::Result<Self::Input<'_>> {
+        parse(input)
+    }
+    fn part1(input: &Self::Input<'_>, _: &Self::Params) -> eyre::Result<impl std::fmt::Display> {
+        part1(input)
     }
-    #[test]
-    fn part2_works() -> eyre::Result<()> {
-        assert_eq!(super::part2(INPUT)?, 6742839);
-        Ok(())
+    fn part2(input: &Self::Input<'_>, _: &Self::Params) -> eyre::Result<impl std::fmt::Display> {
+        part2(input)
     }
 }
+aoc_util::register!(Day);
+
+aoc_util::examples! {
+    Day;
+    example: "abcdef" => (609043, 6742839),
+}
@@ -1,6 +1,10 @@
 use itertools::Itertools;
 
-pub fn part1(input: &str) -> eyre::Result<usize> {
+pub fn parse(input: &str) -> eyre::Result<Vec<&str>> {
+    Ok(input.lines().collect())
+}
+
+pub fn part1(strings: &[&str]) -> eyre::Result<usize> {
     fn is_nice(input: &&str) -> bool {
         const VOWELS: &[char] = &['a', 'e', 'i', 'o', 'u'];
         const DISALLOWED: &[&str] = &["ab", "cd", "pq", "xy"];
@@ -12,9 +16,9 @@ pub fn part1(input: &str) -> eyre::Result<usize> {
         enough_vowels && allowed && has_pair
     }
 
-    Ok(input.lines().filter(is_nice).count())
+    Ok(strings.iter().filter(|s| is_nice(s)).count())
 }
-pub fn part2(input: &str) -> eyre::Result<usize> {
+pub fn part2(strings: &[&str]) -> eyre::Result<usize> {
     fn is_nice(input: &&str) -> bool {
         let mut has_non_overlapping_match = false;
 
@@ -32,22 +36,33 @@ pub fn part2(input: &str) -> eyre::Result<usize> {
         let has_straddled_pair = input.chars().tuple_windows().any(|(a, _, b)| a == b);
         has_non_overlapping_match && has_straddled_pair
     }
-    Ok(input.lines().filter(is_nice).count())
+    Ok(strings.iter().filter(|s| is_nice(s)).count())
 }
 
-#[cfg(test)]
-mod tests {
+pub struct Day;
+
+impl aoc_util::Solution for Day {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 5;
+    const TITLE: &'static str = "Doesn't He Have Intern-Elves For This?";
+
+    type Input<'a> = Vec<&'a str>;
+    type Params = ();
 
-    #[test]
-    fn part1_works() -> eyre::Result<()> {
-        const INPUT: &str = "ugknbfddgicrmopn";
-        assert_eq!(super::part1(INPUT)?, 1);
-        Ok(())
+    fn parse(input: &str) -> eyre::Result<Self::Input<'_>> {
+        parse(input)
     }
-    #[test]
-    fn part2_works() -> eyre::Result<()> {
-        const INPUT: &str = "qjhvhtzxzqqjkmpb";
-        assert_eq!(super::part2(INPUT)?, 1);
-        Ok(())
+    fn part1(input: &Self::Input<'_>, _: &Self::Params) -> eyre::Result<impl std::fmt::Display> {
+        part1(input)
     }
+    fn part2(input: &Self::Input<'_>, _: &Self::Params) -> eyre::Result<impl std::fmt::Display> {
+        part2(input)
+    }
+}
+aoc_util::register!(Day);
+
+aoc_util::examples! {
+    Day;
+    example: "ugknbfddgicrmopn" => part1 = 1,
+    second: "qjhvhtzxzqqjkmpb" => part2 = 1,
 }
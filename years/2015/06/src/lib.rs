@@ -8,13 +8,13 @@ use aoc_util::parse::nom::{
 };
 
 #[derive(Copy, Clone, Debug)]
-enum Action {
+pub enum Action {
     TurnOn,
     TurnOff,
     Toggle,
 }
 #[derive(Copy, Clone, Debug)]
-struct Instruction {
+pub struct Instruction {
     action: Action,
     from: glam::USizeVec2,
     to: glam::USizeVec2,
@@ -45,11 +45,20 @@ impl Instruction {
     }
 }
 
-pub fn part1(input: &str) -> eyre::Result<usize> {
-    let mut lights = [[false; 1000]; 1000];
-    let instructions = input
+pub fn parse(input: &str) -> eyre::Result<Vec<Instruction>> {
+    input
         .lines()
-        .filter_map(|l| Instruction::parse(l).map(|r| r.1).ok());
+        .filter(|l| !l.is_empty())
+        .map(|l| {
+            Instruction::parse(l)
+                .map(|(_, instruction)| instruction)
+                .map_err(|e| eyre::format_err!("parse error: {e}"))
+        })
+        .collect()
+}
+
+pub fn part1(instructions: &[Instruction]) -> eyre::Result<usize> {
+    let mut lights = [[false; 1000]; 1000];
     for instruction in instructions {
         for row in &mut lights[instruction.from.x..=instruction.to.x] {
             for cell in &mut row[instruction.from.y..=instruction.to.y] {
@@ -64,11 +73,8 @@ pub fn part1(input: &str) -> eyre::Result<usize> {
 
     Ok(lights.iter().flatten().filter(|v| **v).count())
 }
-pub fn part2(input: &str) -> eyre::Result<u32> {
+pub fn part2(instructions: &[Instruction]) -> eyre::Result<u32> {
     let mut lights = [[0u32; 1000]; 1000];
-    let instructions = input
-        .lines()
-        .filter_map(|l| Instruction::parse(l).map(|r| r.1).ok());
     for instruction in instructions {
         for row in &mut lights[instruction.from.x..=instruction.to.x] {
             for cell in &mut row[instruction.from.y..=instruction.to.y] {
@@ -81,5 +87,27 @@ pub fn part2(input: &str) -> eyre::Result<u32> {
         }
     }
 
-    Ok(lights.iter().flatten().sum::<u32>() as u32)
+    Ok(lights.iter().flatten().sum::<u32>())
+}
+
+pub struct Day;
+
+impl aoc_util::Solution for Day {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 6;
+    const TITLE: &'static str = "Probably a Fire Hazard";
+
+    type Input<'a> = Vec<Instruction>;
+    type Params = ();
+
+    fn parse(input: &str) -> eyre::Result<Self::Input<'_>> {
+        parse(input)
+    }
+    fn part1(input: &Self::Input<'_>, _: &Self::Params) -> eyre::Result<impl std::fmt::Display> {
+        part1(input)
+    }
+    fn part2(input: &Self::Input<'_>, _: &Self::Params) -> eyre::Result<impl std::fmt::Display> {
+        part2(input)
+    }
 }
+aoc_util::register!(Day);
@@ -13,7 +13,7 @@ use aoc_util::parse::{
 };
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, derive_more::Display)]
-enum Command<'a> {
+pub enum Command<'a> {
     #[display("SET {_0}")]
     Set(Value<'a>),
     #[display("{_0} AND {_1}")]
@@ -29,7 +29,7 @@ enum Command<'a> {
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, derive_more::Display)]
-enum Value<'a> {
+pub enum Value<'a> {
     Ref(&'a str),
     Number(u16),
 }
@@ -37,8 +37,8 @@ enum Value<'a> {
 fn parse_command(input: &str) -> IResult<&str, Command<'_>> {
     let parse_value = || {
         alt((
-            map(alpha1, |s| Value::Ref(s)),
-            map(parse_num, |v| Value::Number(v)),
+            map(alpha1, Value::Ref),
+            map(parse_num, Value::Number),
         ))
     };
 
@@ -60,7 +60,7 @@ fn parse_command(input: &str) -> IResult<&str, Command<'_>> {
             |(a, b)| Command::RShift(a, b),
         ),
         map(tag("NOT ").and(parse_value()), |(_, v)| Command::Not(v)),
-        map(parse_value(), |v| Command::Set(v)),
+        map(parse_value(), Command::Set),
     ))
     .parse(input)
 }
@@ -70,7 +70,7 @@ fn parse_instruction(input: &str) -> IResult<&str, (Command<'_>, &str)> {
 
 fn resolve<'a>(
     value: &Value<'a>,
-    instructions: &HashMap<&'a str, Command<'a>>,
+    instructions: &Circuit<'a>,
     state: &mut HashMap<&'a str, u16>,
 ) -> Result<u16, eyre::Error> {
     match value {
@@ -80,7 +80,7 @@ fn resolve<'a>(
 }
 
 fn calculate_wire<'a>(
-    instructions: &HashMap<&'a str, Command<'a>>,
+    instructions: &Circuit<'a>,
     state: &mut HashMap<&'a str, u16>,
     wire: &'a str,
 ) -> eyre::Result<u16> {
@@ -93,27 +93,27 @@ fn calculate_wire<'a>(
         .ok_or(eyre::format_err!("no wire: {wire}"))?;
 
     let value = match command {
-        Command::Set(value) => resolve(value, &instructions, state)?,
+        Command::Set(value) => resolve(value, instructions, state)?,
         Command::And(left, right) => {
-            let left = resolve(left, &instructions, state)?;
-            let right = resolve(right, &instructions, state)?;
+            let left = resolve(left, instructions, state)?;
+            let right = resolve(right, instructions, state)?;
             left & right
         }
         Command::Or(left, right) => {
-            let left = resolve(left, &instructions, state)?;
-            let right = resolve(right, &instructions, state)?;
+            let left = resolve(left, instructions, state)?;
+            let right = resolve(right, instructions, state)?;
             left | right
         }
         Command::LShift(value, amount) => {
-            let value = resolve(value, &instructions, state)?;
+            let value = resolve(value, instructions, state)?;
             value << amount
         }
         Command::RShift(value, amount) => {
-            let value = resolve(value, &instructions, state)?;
+            let value = resolve(value, instructions, state)?;
             value >> amount
         }
         Command::Not(value) => {
-            let value = resolve(value, &instructions, state)?;
+            let value = resolve(value, instructions, state)?;
             !value
         }
     };
@@ -123,42 +123,72 @@ fn calculate_wire<'a>(
     Ok(value)
 }
 
-fn calculate_wires<'a>(input: &'a str, state: &mut HashMap<&'a str, u16>) -> eyre::Result<()> {
-    let instructions = input
+/// Every wire and the command driving it
+pub type Circuit<'a> = HashMap<&'a str, Command<'a>>;
+
+pub fn parse(input: &str) -> eyre::Result<Circuit<'_>> {
+    input
         .lines()
-        .filter_map(|line| {
+        .map(|line| {
             parse_instruction(line)
                 .map(|(_, (command, wire))| (wire, command))
-                .ok()
+                .map_err(|e| eyre::format_err!("parse error: {e}"))
         })
-        .collect::<HashMap<&str, Command<'_>>>();
+        .collect()
+}
 
+fn calculate_wires<'a>(
+    instructions: &Circuit<'a>,
+    state: &mut HashMap<&'a str, u16>,
+) -> eyre::Result<()> {
     for wire in instructions.keys().copied() {
-        calculate_wire(&instructions, state, wire)?;
+        calculate_wire(instructions, state, wire)?;
     }
 
     Ok(())
 }
 
-pub fn part1(input: &str) -> eyre::Result<u16> {
+pub fn part1(circuit: &Circuit<'_>) -> eyre::Result<u16> {
     let mut wires = HashMap::new();
-    calculate_wires(input, &mut wires)?;
+    calculate_wires(circuit, &mut wires)?;
     Ok(wires["a"])
 }
 
-pub fn part2(input: &str) -> eyre::Result<u16> {
+pub fn part2(circuit: &Circuit<'_>) -> eyre::Result<u16> {
     let mut wires = HashMap::new();
-    let a = part1(input)?;
+    let a = part1(circuit)?;
 
     wires.insert("b", a);
-    calculate_wires(input, &mut wires)?;
+    calculate_wires(circuit, &mut wires)?;
 
     Ok(wires["a"])
 }
 
-#[cfg(test)]
-mod tests {
-    const INPUT: &str = "123 -> x
+pub struct Day;
+
+impl aoc_util::Solution for Day {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 7;
+    const TITLE: &'static str = "Some Assembly Required";
+
+    type Input<'a> = Circuit<'a>;
+    type Params = ();
+
+    fn parse(input: &str) -> eyre::Result<Self::Input<'_>> {
+        parse(input)
+    }
+    fn part1(input: &Self::Input<'_>, _: &Self::Params) -> eyre::Result<impl std::fmt::Display> {
+        part1(input)
+    }
+    fn part2(input: &Self::Input<'_>, _: &Self::Params) -> eyre::Result<impl std::fmt::Display> {
+        part2(input)
+    }
+}
+aoc_util::register!(Day);
+
+aoc_util::examples! {
+    Day;
+    example: "123 -> x
 456 -> y
 x AND y -> a
 x OR y -> e
@@ -166,16 +196,5 @@ x LSHIFT 2 -> f
 y RSHIFT 2 -> g
 NOT x -> h
 NOT y -> i
-";
-
-    #[test]
-    fn part1_works() -> eyre::Result<()> {
-        assert_eq!(super::part1(INPUT)?, 72);
-        Ok(())
-    }
-    #[test]
-    fn part2_works() -> eyre::Result<()> {
-        assert_eq!(super::part2(INPUT)?, 72);
-        Ok(())
-    }
+" => (72, 72),
 }
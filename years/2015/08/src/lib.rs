@@ -9,17 +9,21 @@ use aoc_util::parse::nom::{
 
 fn parser(input: &str) -> IResult<&str, char> {
     let hex = map_res(take(2usize), |s| u8::from_str_radix(s, 16));
-    let hex_char = map(preceded(tag(r"\x"), hex), |u| u as u8 as char);
+    let hex_char = map(preceded(tag(r"\x"), hex), |u| u as char);
     let escaped_char = preceded(char('\\'), anychar);
 
     alt((char('"'), hex_char, escaped_char, anychar)).parse(input)
 }
 
-pub fn part1(input: &str) -> eyre::Result<usize> {
+pub fn parse(input: &str) -> eyre::Result<Vec<&str>> {
+    Ok(input.lines().collect())
+}
+
+pub fn part1(lines: &[&str]) -> eyre::Result<usize> {
     let mut total = 0;
     let mut actual = 0;
 
-    for mut line in input.lines() {
+    for mut line in lines.iter().copied() {
         total += line.len();
         line = &line[1..line.len() - 1];
 
@@ -33,25 +37,40 @@ pub fn part1(input: &str) -> eyre::Result<usize> {
 
     Ok(total - actual)
 }
-pub fn part2(_: &str) -> eyre::Result<u32> {
+pub fn part2(_: &[&str]) -> eyre::Result<u32> {
     Ok(0)
 }
 
-#[cfg(test)]
-mod tests {
-    const INPUT: &str = r#""qxfcsmh""#;
+pub struct Day;
 
-    #[test]
-    fn part1_works() -> eyre::Result<()> {
-        assert_eq!(super::part1(INPUT)?, 2);
-        Ok(())
+impl aoc_util::Solution for Day {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 8;
+    const TITLE: &'static str = "Matchsticks";
+    const STUBS: &'static [aoc_util::Part] = &[aoc_util::Part::Two];
+
+    type Input<'a> = Vec<&'a str>;
+    type Params = ();
+
+    fn parse(input: &str) -> eyre::Result<Self::Input<'_>> {
+        parse(input)
     }
-    #[test]
-    fn part2_works() -> eyre::Result<()> {
-        assert_eq!(super::part2(INPUT)?, 0);
-        Ok(())
+    fn part1(input: &Self::Input<'_>, _: &Self::Params) -> eyre::Result<impl std::fmt::Display> {
+        part1(input)
     }
+    fn part2(input: &Self::Input<'_>, _: &Self::Params) -> eyre::Result<impl std::fmt::Display> {
+        part2(input)
+    }
+}
+aoc_util::register!(Day);
 
+aoc_util::examples! {
+    Day;
+    example: r#""qxfcsmh""# => (2, _),
+}
+
+#[cfg(test)]
+mod tests {
     #[test]
     fn parser() -> eyre::Result<()> {
         assert_eq!(("", 0x27 as char), super::parser(r"\x27")?);
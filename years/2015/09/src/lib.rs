@@ -10,7 +10,7 @@ use aoc_util::parse::nom::{
 };
 use petgraph::prelude::UnGraphMap;
 
-fn parse(input: &str) -> IResult<&str, UnGraphMap<&str, u16>> {
+fn graph(input: &str) -> IResult<&str, UnGraphMap<&str, u16>> {
     let cities = separated_pair(alpha1, tag(" to "), alpha1);
     let route = map(separated_pair(cities, tag(" = "), u16), |((l, r), w)| {
         (l, r, w)
@@ -18,6 +18,11 @@ fn parse(input: &str) -> IResult<&str, UnGraphMap<&str, u16>> {
     map(separated_list1(newline, route), UnGraphMap::from_iter).parse(input)
 }
 
+pub fn parse(input: &str) -> eyre::Result<UnGraphMap<&str, u16>> {
+    let (_, graph) = graph(input).map_err(|e| eyre::format_err!("parse error: {e}"))?;
+    Ok(graph)
+}
+
 fn path_length(graph: &UnGraphMap<&str, u16>, min_or_max: bool) -> eyre::Result<u16> {
     let mut result = if min_or_max { u16::MAX } else { 0 };
     let mut visited = HashSet::with_capacity(graph.node_count());
@@ -62,29 +67,38 @@ fn path_length(graph: &UnGraphMap<&str, u16>, min_or_max: bool) -> eyre::Result<
     Ok(result)
 }
 
-pub fn part1(input: &str) -> eyre::Result<u16> {
-    let (_, graph) = parse(input).map_err(|e| eyre::format_err!("parse error: {e}"))?;
-    path_length(&graph, true)
+pub fn part1(graph: &UnGraphMap<&str, u16>) -> eyre::Result<u16> {
+    path_length(graph, true)
 }
-pub fn part2(input: &str) -> eyre::Result<u16> {
-    let (_, graph) = parse(input).map_err(|e| eyre::format_err!("parse error: {e}"))?;
-    path_length(&graph, false)
+pub fn part2(graph: &UnGraphMap<&str, u16>) -> eyre::Result<u16> {
+    path_length(graph, false)
 }
 
-#[cfg(test)]
-mod tests {
-    const INPUT: &str = "London to Dublin = 464
-London to Belfast = 518
-Dublin to Belfast = 141";
+pub struct Day;
+
+impl aoc_util::Solution for Day {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 9;
+    const TITLE: &'static str = "All in a Single Night";
+
+    type Input<'a> = UnGraphMap<&'a str, u16>;
+    type Params = ();
 
-    #[test]
-    fn part1_works() -> eyre::Result<()> {
-        assert_eq!(super::part1(INPUT)?, 605);
-        Ok(())
+    fn parse(input: &str) -> eyre::Result<Self::Input<'_>> {
+        parse(input)
     }
-    #[test]
-    fn part2_works() -> eyre::Result<()> {
-        assert_eq!(super::part2(INPUT)?, 982);
-        Ok(())
+    fn part1(input: &Self::Input<'_>, _: &Self::Params) -> eyre::Result<impl std::fmt::Display> {
+        part1(input)
     }
+    fn part2(input: &Self::Input<'_>, _: &Self::Params) -> eyre::Result<impl std::fmt::Display> {
+        part2(input)
+    }
+}
+aoc_util::register!(Day);
+
+aoc_util::examples! {
+    Day;
+    example: "London to Dublin = 464
+London to Belfast = 518
+Dublin to Belfast = 141" => (605, 982),
 }
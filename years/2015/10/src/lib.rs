@@ -4,8 +4,7 @@ fn process(mut input: &str) -> eyre::Result<String> {
     let mut count = 1;
     let mut out = String::with_capacity(input.len());
     let mut current = input
-        .chars()
-        .nth(0)
+        .chars().next()
         .ok_or(eyre::format_err!("empty input"))?;
 
     for (i, c) in input.chars().enumerate().skip(1) {
@@ -23,19 +22,46 @@ fn process(mut input: &str) -> eyre::Result<String> {
     Ok(out)
 }
 
-pub fn part1(input: &str) -> eyre::Result<usize> {
-    let mut out = input.to_owned();
+/// The starting sequence
+pub fn parse(input: &str) -> eyre::Result<&str> {
+    Ok(input.trim())
+}
+
+pub fn part1(start: &str) -> eyre::Result<usize> {
+    let mut out = start.to_owned();
     for _ in 0..40 {
         out = process(&out)?;
     }
 
     Ok(out.len())
 }
-pub fn part2(input: &str) -> eyre::Result<usize> {
-    let mut out = input.to_owned();
+pub fn part2(start: &str) -> eyre::Result<usize> {
+    let mut out = start.to_owned();
     for _ in 0..50 {
         out = process(&out)?;
     }
 
     Ok(out.len())
 }
+
+pub struct Day;
+
+impl aoc_util::Solution for Day {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 10;
+    const TITLE: &'static str = "Elves Look, Elves Say";
+
+    type Input<'a> = &'a str;
+    type Params = ();
+
+    fn parse(input: &str) -> eyre::Result<Self::Input<'_>> {
+        parse(input)
+    }
+    fn part1(input: &Self::Input<'_>, _: &Self::Params) -> eyre::Result<impl std::fmt::Display> {
+        part1(input)
+    }
+    fn part2(input: &Self::Input<'_>, _: &Self::Params) -> eyre::Result<impl std::fmt::Display> {
+        part2(input)
+    }
+}
+aoc_util::register!(Day);
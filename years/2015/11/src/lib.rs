@@ -67,7 +67,7 @@ fn is_allowed(mut input: &str) -> bool {
         return false;
     }
 
-    return true;
+    true
 }
 
 fn next_password(input: &str) -> eyre::Result<String> {
@@ -82,16 +82,43 @@ fn next_password(input: &str) -> eyre::Result<String> {
     Ok(result)
 }
 
-pub fn part1(input: &str) -> eyre::Result<String> {
-    next_password(input)
+/// Santa's current password
+pub fn parse(input: &str) -> eyre::Result<&str> {
+    Ok(input.trim())
 }
-pub fn part2(input: &str) -> eyre::Result<String> {
-    let mut pass = next_password(input)?;
+
+pub fn part1(password: &str) -> eyre::Result<String> {
+    next_password(password)
+}
+pub fn part2(password: &str) -> eyre::Result<String> {
+    let mut pass = next_password(password)?;
     pass = next_password(&pass)?;
 
     Ok(pass)
 }
 
+pub struct Day;
+
+impl aoc_util::Solution for Day {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 11;
+    const TITLE: &'static str = "Corporate Policy";
+
+    type Input<'a> = &'a str;
+    type Params = ();
+
+    fn parse(input: &str) -> eyre::Result<Self::Input<'_>> {
+        parse(input)
+    }
+    fn part1(input: &Self::Input<'_>, _: &Self::Params) -> eyre::Result<impl std::fmt::Display> {
+        part1(input)
+    }
+    fn part2(input: &Self::Input<'_>, _: &Self::Params) -> eyre::Result<impl std::fmt::Display> {
+        part2(input)
+    }
+}
+aoc_util::register!(Day);
+
 #[cfg(test)]
 mod tests {
     #[test]
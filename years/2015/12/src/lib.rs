@@ -1,4 +1,8 @@
-pub fn part1(input: &str) -> eyre::Result<i64> {
+pub fn parse(input: &str) -> eyre::Result<serde_json::Value> {
+    Ok(serde_json::de::from_str(input)?)
+}
+
+pub fn part1(document: &serde_json::Value) -> eyre::Result<i64> {
     fn visit(value: &serde_json::Value) -> i64 {
         match value {
             serde_json::Value::Number(number) => number.as_i64().unwrap_or_default(),
@@ -8,10 +12,9 @@ pub fn part1(input: &str) -> eyre::Result<i64> {
         }
     }
 
-    let value: serde_json::Value = serde_json::de::from_str(input)?;
-    Ok(visit(&value))
+    Ok(visit(document))
 }
-pub fn part2(input: &str) -> eyre::Result<i64> {
+pub fn part2(document: &serde_json::Value) -> eyre::Result<i64> {
     fn visit(value: &serde_json::Value) -> i64 {
         match value {
             serde_json::Value::Number(number) => number.as_i64().unwrap_or_default(),
@@ -30,22 +33,32 @@ pub fn part2(input: &str) -> eyre::Result<i64> {
         }
     }
 
-    let value: serde_json::Value = serde_json::de::from_str(input)?;
-    Ok(visit(&value))
+    Ok(visit(document))
 }
 
-#[cfg(test)]
-mod tests {
-    const INPUT: &str = r#"{"red": [1, 2, 3], "green": 6, "test": "red"}"#;
+pub struct Day;
+
+impl aoc_util::Solution for Day {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 12;
+    const TITLE: &'static str = "JSAbacusFramework.io";
+
+    type Input<'a> = serde_json::Value;
+    type Params = ();
 
-    #[test]
-    fn part1_works() -> eyre::Result<()> {
-        assert_eq!(super::part1(INPUT)?, 12);
-        Ok(())
+    fn parse(input: &str) -> eyre::Result<Self::Input<'_>> {
+        parse(input)
     }
-    #[test]
-    fn part2_works() -> eyre::Result<()> {
-        assert_eq!(super::part2(INPUT)?, 0);
-        Ok(())
+    fn part1(input: &Self::Input<'_>, _: &Self::Params) -> eyre::Result<impl std::fmt::Display> {
+        part1(input)
     }
+    fn part2(input: &Self::Input<'_>, _: &Self::Params) -> eyre::Result<impl std::fmt::Display> {
+        part2(input)
+    }
+}
+aoc_util::register!(Day);
+
+aoc_util::examples! {
+    Day;
+    example: r#"{"red": [1, 2, 3], "green": 6, "test": "red"}"# => (12, 0),
 }
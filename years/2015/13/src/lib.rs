@@ -15,7 +15,7 @@ use aoc_util::parse::{
 use itertools::Itertools;
 use petgraph::prelude::DiGraphMap;
 
-fn parse(input: &str) -> eyre::Result<DiGraphMap<&str, i16>> {
+pub fn parse(input: &str) -> eyre::Result<DiGraphMap<&str, i16>> {
     let parse_potential = (
         alpha1,
         tag(" would "),
@@ -52,14 +52,13 @@ fn max_happiness(graph: &DiGraphMap<&str, i16>) -> i16 {
         .unwrap_or_default()
 }
 
-pub fn part1(input: &str) -> eyre::Result<i16> {
-    let graph = parse(input)?;
-    Ok(max_happiness(&graph))
+pub fn part1(graph: &DiGraphMap<&str, i16>) -> eyre::Result<i16> {
+    Ok(max_happiness(graph))
 }
-pub fn part2(input: &str) -> eyre::Result<i16> {
+pub fn part2(graph: &DiGraphMap<&str, i16>) -> eyre::Result<i16> {
     const ME: &str = "Me";
 
-    let mut graph = parse(input)?;
+    let mut graph = graph.clone();
     for node in graph.nodes().collect_vec() {
         graph.add_edge(ME, node, 0);
         graph.add_edge(node, ME, 0);
@@ -68,9 +67,31 @@ pub fn part2(input: &str) -> eyre::Result<i16> {
     Ok(max_happiness(&graph))
 }
 
-#[cfg(test)]
-mod tests {
-    const INPUT: &str = "Alice would gain 54 happiness units by sitting next to Bob.
+pub struct Day;
+
+impl aoc_util::Solution for Day {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 13;
+    const TITLE: &'static str = "Knights of the Dinner Table";
+
+    type Input<'a> = DiGraphMap<&'a str, i16>;
+    type Params = ();
+
+    fn parse(input: &str) -> eyre::Result<Self::Input<'_>> {
+        parse(input)
+    }
+    fn part1(input: &Self::Input<'_>, _: &Self::Params) -> eyre::Result<impl std::fmt::Display> {
+        part1(input)
+    }
+    fn part2(input: &Self::Input<'_>, _: &Self::Params) -> eyre::Result<impl std::fmt::Display> {
+        part2(input)
+    }
+}
+aoc_util::register!(Day);
+
+aoc_util::examples! {
+    Day;
+    example: "Alice would gain 54 happiness units by sitting next to Bob.
 Alice would lose 79 happiness units by sitting next to Carol.
 Alice would lose 2 happiness units by sitting next to David.
 Bob would gain 83 happiness units by sitting next to Alice.
@@ -81,16 +102,5 @@ Carol would gain 60 happiness units by sitting next to Bob.
 Carol would gain 55 happiness units by sitting next to David.
 David would gain 46 happiness units by sitting next to Alice.
 David would lose 7 happiness units by sitting next to Bob.
-David would gain 41 happiness units by sitting next to Carol.";
-
-    #[test]
-    fn part1_works() -> eyre::Result<()> {
-        assert_eq!(super::part1(INPUT)?, 330);
-        Ok(())
-    }
-    #[test]
-    fn part2_works() -> eyre::Result<()> {
-        assert_eq!(super::part2(INPUT)?, 286);
-        Ok(())
-    }
+David would gain 41 happiness units by sitting next to Carol." => (330, 286),
 }
@@ -8,7 +8,7 @@ use aoc_util::parse::nom::{
 use itertools::Itertools;
 
 #[derive(Debug)]
-struct Reindeer {
+pub struct Reindeer {
     speed: u16,
     fly_seconds: u16,
     rest_seconds: u16,
@@ -22,7 +22,7 @@ struct State {
     score: u16,
 }
 
-fn parse(input: &str) -> IResult<&str, Vec<Reindeer>> {
+fn reindeer(input: &str) -> IResult<&str, Vec<Reindeer>> {
     let reindeer = (
         alpha1,
         tag(" can fly "),
@@ -40,7 +40,7 @@ fn parse(input: &str) -> IResult<&str, Vec<Reindeer>> {
     separated_list1(newline, reindeer).parse(input)
 }
 
-fn run_race(reindeer: &Vec<Reindeer>, mut seconds: u16) -> Vec<State> {
+fn run_race(reindeer: &[Reindeer], mut seconds: u16) -> Vec<State> {
     let mut states = reindeer
         .iter()
         .map(|r| State {
@@ -81,29 +81,52 @@ fn run_race(reindeer: &Vec<Reindeer>, mut seconds: u16) -> Vec<State> {
     states
 }
 
-pub fn part1(input: &str) -> eyre::Result<u16> {
-    let (_, reindeer) = parse(input).map_err(|e| eyre::format_err!("parse error: {e}"))?;
-    let results = run_race(&reindeer, 2503);
+pub fn parse(input: &str) -> eyre::Result<Vec<Reindeer>> {
+    let (_, reindeer) = reindeer(input).map_err(|e| eyre::format_err!("parse error: {e}"))?;
+    Ok(reindeer)
+}
+
+aoc_util::params! {
+    pub struct Params {
+        /// How long the race lasts
+        seconds: u16 = 2503,
+    }
+}
+
+pub fn part1(reindeer: &[Reindeer], params: &Params) -> eyre::Result<u16> {
+    let results = run_race(reindeer, params.seconds);
     Ok(results.iter().map(|r| r.distance).max().unwrap())
 }
-pub fn part2(input: &str) -> eyre::Result<u16> {
-    let (_, reindeer) = parse(input).map_err(|e| eyre::format_err!("parse error: {e}"))?;
-    let results = run_race(&reindeer, 2503);
+pub fn part2(reindeer: &[Reindeer], params: &Params) -> eyre::Result<u16> {
+    let results = run_race(reindeer, params.seconds);
     Ok(results.iter().map(|r| r.score).max().unwrap())
 }
 
-#[cfg(test)]
-mod tests {
-    const INPUT: &str = "Comet can fly 14 km/s for 10 seconds, but then must rest for 127 seconds.";
+pub struct Day;
 
-    #[test]
-    fn part1_works() -> eyre::Result<()> {
-        assert_eq!(super::part1(INPUT)?, 2660);
-        Ok(())
+impl aoc_util::Solution for Day {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 14;
+    const TITLE: &'static str = "Reindeer Olympics";
+
+    type Input<'a> = Vec<Reindeer>;
+    type Params = Params;
+
+    fn parse(input: &str) -> eyre::Result<Self::Input<'_>> {
+        parse(input)
+    }
+    fn part1(input: &Self::Input<'_>, params: &Params) -> eyre::Result<impl std::fmt::Display> {
+        part1(input, params)
     }
-    #[test]
-    fn part2_works() -> eyre::Result<()> {
-        assert_eq!(super::part2(INPUT)?, 2503);
-        Ok(())
+    fn part2(input: &Self::Input<'_>, params: &Params) -> eyre::Result<impl std::fmt::Display> {
+        part2(input, params)
     }
 }
+aoc_util::register!(Day);
+
+aoc_util::examples! {
+    Day;
+    comet: "Comet can fly 14 km/s for 10 seconds, but then must rest for 127 seconds." => (2660, 2503),
+    example { seconds: 1000 }: "Comet can fly 14 km/s for 10 seconds, but then must rest for 127 seconds.
+Dancer can fly 16 km/s for 11 seconds, but then must rest for 162 seconds." => (1120, 689),
+}
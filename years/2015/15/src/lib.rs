@@ -13,9 +13,9 @@ use aoc_util::parse::nom::{
 use itertools::Itertools;
 
 // name -> (category -> score)
-type Ingredients<'a> = Vec<(&'a str, HashMap<&'a str, i8>)>;
+pub type Ingredients<'a> = Vec<(&'a str, HashMap<&'a str, i8>)>;
 
-fn parse(input: &str) -> IResult<&str, Ingredients<'_>> {
+fn ingredients(input: &str) -> IResult<&str, Ingredients<'_>> {
     separated_list1(
         newline,
         (
@@ -49,25 +49,26 @@ fn evaluate(ingredients: &Ingredients<'_>, amounts: &[i64]) -> (i64, i64) {
     )
 }
 
-pub fn part1(input: &str) -> eyre::Result<i64> {
-    let (_, ingredients) = parse(input).map_err(|e| eyre::format_err!("{e}"))?;
+pub fn parse(input: &str) -> eyre::Result<Ingredients<'_>> {
+    let (_, ingredients) = ingredients(input).map_err(|e| eyre::format_err!("{e}"))?;
+    Ok(ingredients)
+}
 
+pub fn part1(ingredients: &Ingredients<'_>) -> eyre::Result<i64> {
     let best = (0..=100i64)
         .permutations(ingredients.len())
         .filter(|a| a.iter().sum::<i64>() == 100)
-        .map(|amounts| evaluate(&ingredients, &amounts))
+        .map(|amounts| evaluate(ingredients, &amounts))
         .max_by_key(|s| s.0)
         .unwrap();
 
     Ok(best.0)
 }
-pub fn part2(input: &str) -> eyre::Result<i64> {
-    let (_, ingredients) = parse(input).map_err(|e| eyre::format_err!("{e}"))?;
-
+pub fn part2(ingredients: &Ingredients<'_>) -> eyre::Result<i64> {
     let best = (0..=100i64)
         .permutations(ingredients.len())
         .filter(|a| a.iter().sum::<i64>() == 100)
-        .map(|amounts| evaluate(&ingredients, &amounts))
+        .map(|amounts| evaluate(ingredients, &amounts))
         .filter(|(_, calories)| *calories == 500)
         .max_by_key(|(score, _)| *score)
         .unwrap();
@@ -75,15 +76,43 @@ pub fn part2(input: &str) -> eyre::Result<i64> {
     Ok(best.0)
 }
 
+pub struct Day;
+
+impl aoc_util::Solution for Day {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 15;
+    const TITLE: &'static str = "Science for Hungry People";
+
+    type Input<'a> = Ingredients<'a>;
+    type Params = ();
+
+    fn parse(input: &str) -> eyre::Result<Self::Input<'_>> {
+        parse(input)
+    }
+    fn part1(input: &Self::Input<'_>, _: &Self::Params) -> eyre::Result<impl std::fmt::Display> {
+        part1(input)
+    }
+    fn part2(input: &Self::Input<'_>, _: &Self::Params) -> eyre::Result<impl std::fmt::Display> {
+        part2(input)
+    }
+}
+aoc_util::register!(Day);
+
+#[cfg(test)]
+const INPUT: &str = "Butterscotch: capacity -1, durability -2, flavor 6, texture 3, calories 8
+Cinnamon: capacity 2, durability 3, flavor -2, texture -1, calories 3";
+
+aoc_util::examples! {
+    Day;
+    example: INPUT => (62842880, 57600000),
+}
+
 #[cfg(test)]
 mod tests {
     use std::collections::HashMap;
 
     use crate::Ingredients;
 
-    const INPUT: &str = "Butterscotch: capacity -1, durability -2, flavor 6, texture 3, calories 8
-Cinnamon: capacity 2, durability 3, flavor -2, texture -1, calories 3";
-
     fn expected() -> Ingredients<'static> {
         vec![
             (
@@ -109,19 +138,9 @@ Cinnamon: capacity 2, durability 3, flavor -2, texture -1, calories 3";
         ]
     }
 
-    #[test]
-    fn part1_works() -> eyre::Result<()> {
-        assert_eq!(super::part1(INPUT)?, 62842880);
-        Ok(())
-    }
-    #[test]
-    fn part2_works() -> eyre::Result<()> {
-        assert_eq!(super::part2(INPUT)?, 57600000);
-        Ok(())
-    }
     #[test]
     fn parse_works() -> eyre::Result<()> {
-        assert_eq!(super::parse(INPUT)?.1, expected());
+        assert_eq!(super::parse(super::INPUT)?, expected());
         Ok(())
     }
     #[test]
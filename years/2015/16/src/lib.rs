@@ -26,7 +26,7 @@ static TAPE: LazyLock<HashMap<&str, u16>> = LazyLock::new(|| {
     ])
 });
 
-struct AuntSue<'a> {
+pub struct AuntSue<'a> {
     id: u16,
     things: HashMap<&'a str, u16>,
 }
@@ -44,13 +44,14 @@ fn parse_sue(input: &str) -> IResult<&str, AuntSue<'_>> {
     .parse(input)
 }
 
-fn parse(input: &str) -> IResult<&str, Vec<AuntSue<'_>>> {
-    separated_list1(newline, parse_sue).parse(input)
+pub fn parse(input: &str) -> eyre::Result<Vec<AuntSue<'_>>> {
+    let (_, aunts) = separated_list1(newline, parse_sue)
+        .parse(input)
+        .map_err(|e| eyre::format_err!("{e:?}"))?;
+    Ok(aunts)
 }
 
-pub fn part1(input: &str) -> eyre::Result<u16> {
-    let (_, aunts) = parse(input).map_err(|e| eyre::format_err!("{e:?}"))?;
-
+pub fn part1(aunts: &[AuntSue<'_>]) -> eyre::Result<u16> {
     aunts
         .iter()
         .find(|aunt| {
@@ -61,9 +62,7 @@ pub fn part1(input: &str) -> eyre::Result<u16> {
         .map(|aunt| aunt.id)
         .ok_or(eyre::format_err!("no aunt found"))
 }
-pub fn part2(input: &str) -> eyre::Result<u16> {
-    let (_, aunts) = parse(input).map_err(|e| eyre::format_err!("{e:?}"))?;
-
+pub fn part2(aunts: &[AuntSue<'_>]) -> eyre::Result<u16> {
     aunts
         .iter()
         .find(|aunt| {
@@ -79,6 +78,28 @@ pub fn part2(input: &str) -> eyre::Result<u16> {
         .ok_or(eyre::format_err!("no aunt found"))
 }
 
+pub struct Day;
+
+impl aoc_util::Solution for Day {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 16;
+    const TITLE: &'static str = "Aunt Sue";
+
+    type Input<'a> = Vec<AuntSue<'a>>;
+    type Params = ();
+
+    fn parse(input: &str) -> eyre::Result<Self::Input<'_>> {
+        parse(input)
+    }
+    fn part1(input: &Self::Input<'_>, _: &Self::Params) -> eyre::Result<impl std::fmt::Display> {
+        part1(input)
+    }
+    fn part2(input: &Self::Input<'_>, _: &Self::Params) -> eyre::Result<impl std::fmt::Display> {
+        part2(input)
+    }
+}
+aoc_util::register!(Day);
+
 #[cfg(test)]
 mod tests {
     const INPUT: &str = "Sue 100: abc: 1, cde: 2\nSue 101: abc: 1, cde: 2\n";
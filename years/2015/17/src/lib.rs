@@ -5,32 +5,40 @@ use aoc_util::parse::nom::{
 };
 use itertools::Itertools;
 
-fn parse(input: &str) -> IResult<&str, Vec<u32>> {
+fn containers(input: &str) -> IResult<&str, Vec<u32>> {
     separated_list1(newline, u8.map(|i| i as u32)).parse(input)
 }
 
-pub fn part1(input: &str) -> eyre::Result<usize> {
-    let (_, containers) = parse(input).map_err(|e| eyre::format_err!("{e}"))?;
+pub fn parse(input: &str) -> eyre::Result<Vec<u32>> {
+    let (_, containers) = containers(input).map_err(|e| eyre::format_err!("{e}"))?;
+    Ok(containers)
+}
+
+aoc_util::params! {
+    pub struct Params {
+        /// Eggnog to store, filling every container used
+        liters: u32 = 150,
+    }
+}
 
+pub fn part1(containers: &[u32], params: &Params) -> eyre::Result<usize> {
     Ok((2..=containers.len())
         .map(|count| {
             containers
                 .iter()
                 .combinations(count)
-                .filter(|c| c.iter().copied().sum::<u32>() == 150)
+                .filter(|c| c.iter().copied().sum::<u32>() == params.liters)
                 .count()
         })
         .sum())
 }
 
-pub fn part2(input: &str) -> eyre::Result<usize> {
-    let (_, containers) = parse(input).map_err(|e| eyre::format_err!("{e}"))?;
-
+pub fn part2(containers: &[u32], params: &Params) -> eyre::Result<usize> {
     for i in 2..=containers.len() {
         let valid_combinations = containers
             .iter()
             .combinations(i)
-            .filter(|c| c.iter().copied().sum::<u32>() == 150)
+            .filter(|c| c.iter().copied().sum::<u32>() == params.liters)
             .count();
 
         if valid_combinations > 0 {
@@ -41,20 +49,36 @@ pub fn part2(input: &str) -> eyre::Result<usize> {
     eyre::bail!("no combination found");
 }
 
-#[cfg(test)]
-mod tests {
-    const INPUT: &str = "140
-10
-5";
+pub struct Day;
+
+impl aoc_util::Solution for Day {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 17;
+    const TITLE: &'static str = "No Such Thing as Too Much";
 
-    #[test]
-    fn part1_works() -> eyre::Result<()> {
-        assert_eq!(super::part1(INPUT)?, 1);
-        Ok(())
+    type Input<'a> = Vec<u32>;
+    type Params = Params;
+
+    fn parse(input: &str) -> eyre::Result<Self::Input<'_>> {
+        parse(input)
+    }
+    fn part1(input: &Self::Input<'_>, params: &Params) -> eyre::Result<impl std::fmt::Display> {
+        part1(input, params)
     }
-    #[test]
-    fn part2_works() -> eyre::Result<()> {
-        assert_eq!(super::part2(INPUT)?, 1);
-        Ok(())
+    fn part2(input: &Self::Input<'_>, params: &Params) -> eyre::Result<impl std::fmt::Display> {
+        part2(input, params)
     }
 }
+aoc_util::register!(Day);
+
+aoc_util::examples! {
+    Day;
+    pair: "140
+10
+5" => (1, 1),
+    example { liters: 25 }: "20
+15
+10
+5
+5" => (4, 3),
+}
@@ -4,7 +4,7 @@ use aoc_util::glam;
 use itertools::{iproduct, Itertools};
 
 #[derive(Clone)]
-struct Grid {
+pub struct Grid {
     entries: Vec<Vec<bool>>,
 }
 impl Debug for Grid {
@@ -13,7 +13,7 @@ impl Debug for Grid {
             for cell in row {
                 write!(f, "{}", if *cell { '#' } else { '.' })?;
             }
-            writeln!(f, "")?;
+            writeln!(f)?;
         }
 
         Ok(())
@@ -44,12 +44,12 @@ impl Grid {
     }
 }
 
-fn parse(input: &str) -> Grid {
+pub fn parse(input: &str) -> eyre::Result<Grid> {
     let entries = input
         .lines()
         .map(|s| s.chars().map(|c| c == '#').collect_vec())
         .collect_vec();
-    Grid { entries }
+    Ok(Grid { entries })
 }
 
 fn run(current: &Grid, next: &mut Grid, corners: bool) {
@@ -88,36 +88,29 @@ fn run(current: &Grid, next: &mut Grid, corners: bool) {
     }
 }
 
-pub fn part1(input: &str) -> eyre::Result<usize> {
-    let mut grid = parse(input);
+aoc_util::params! {
+    pub struct Params {
+        /// How many times the lights are animated
+        steps: usize = 100,
+    }
+}
 
+pub fn part1(grid: &Grid, params: &Params) -> eyre::Result<usize> {
     let mut current = &mut grid.clone();
-    let mut next = &mut grid;
-
-    #[cfg(not(test))]
-    let range = 0..100;
-    #[cfg(test)]
-    let range = 0..4;
+    let mut next = &mut grid.clone();
 
-    for _ in range {
+    for _ in 0..params.steps {
         run(current, next, false);
         std::mem::swap(&mut current, &mut next);
     }
 
     Ok(current.count())
 }
-pub fn part2(input: &str) -> eyre::Result<usize> {
-    let mut grid = parse(input);
-
+pub fn part2(grid: &Grid, params: &Params) -> eyre::Result<usize> {
     let mut current = &mut grid.clone();
-    let mut next = &mut grid;
-
-    #[cfg(not(test))]
-    let range = 0..100;
-    #[cfg(test)]
-    let range = 0..5;
+    let mut next = &mut grid.clone();
 
-    for _ in range {
+    for _ in 0..params.steps {
         run(current, next, true);
         std::mem::swap(&mut current, &mut next);
     }
@@ -125,31 +118,40 @@ pub fn part2(input: &str) -> eyre::Result<usize> {
     Ok(current.count())
 }
 
-#[cfg(test)]
-mod tests {
+pub struct Day;
+
+impl aoc_util::Solution for Day {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 18;
+    const TITLE: &'static str = "Like a GIF For Your Yard";
+
+    type Input<'a> = Grid;
+    type Params = Params;
+
+    fn parse(input: &str) -> eyre::Result<Self::Input<'_>> {
+        parse(input)
+    }
+    fn part1(input: &Self::Input<'_>, params: &Params) -> eyre::Result<impl std::fmt::Display> {
+        part1(input, params)
+    }
+    fn part2(input: &Self::Input<'_>, params: &Params) -> eyre::Result<impl std::fmt::Display> {
+        part2(input, params)
+    }
+}
+aoc_util::register!(Day);
 
-    #[test]
-    fn part1_works() -> eyre::Result<()> {
-        const INPUT: &str = ".#.#.#
+aoc_util::examples! {
+    Day;
+    example { steps: 4 }: ".#.#.#
 ...##.
 #....#
 ..#...
 #.#..#
-####..";
-
-        assert_eq!(super::part1(INPUT)?, 4);
-        Ok(())
-    }
-    #[test]
-    fn part2_works() -> eyre::Result<()> {
-        const INPUT: &str = "##.#.#
+####.." => part1 = 4,
+    second { steps: 5 }: "##.#.#
 ...##.
 #....#
 ..#...
 #.#..#
-####.#";
-
-        assert_eq!(super::part2(INPUT)?, 17);
-        Ok(())
-    }
+####.#" => part2 = 17,
 }
@@ -8,9 +8,9 @@ use aoc_util::parse::nom::{
     IResult, Parser,
 };
 
-type Mapping<'a> = HashMap<&'a str, Vec<&'a str>>;
+pub type Mapping<'a> = HashMap<&'a str, Vec<&'a str>>;
 
-fn parse(input: &str) -> IResult<&str, (&str, Mapping<'_>)> {
+fn replacements(input: &str) -> IResult<&str, (&str, Mapping<'_>)> {
     let (remain, (mappings, molecule)) = separated_pair(
         separated_list1(newline, separated_pair(alpha1, tag(" => "), alpha1)),
         tag("\n\n"),
@@ -45,9 +45,12 @@ fn next_molecules<'a>(
         })
 }
 
-pub fn part1(input: &str) -> eyre::Result<usize> {
-    let (_, (medicine, mapping)) = parse(input).map_err(|e| eyre::format_err!("{e}"))?;
+pub fn parse(input: &str) -> eyre::Result<(&str, Mapping<'_>)> {
+    let (_, replacements) = replacements(input).map_err(|e| eyre::format_err!("{e}"))?;
+    Ok(replacements)
+}
 
+pub fn part1((medicine, mapping): &(&str, Mapping<'_>)) -> eyre::Result<usize> {
     let mut distinct_molecules = HashSet::new();
 
     for (to_replace, replace_with) in mapping.iter().flat_map(|(k, v)| v.iter().map(|v| (*k, *v))) {
@@ -57,32 +60,47 @@ pub fn part1(input: &str) -> eyre::Result<usize> {
     Ok(distinct_molecules.len())
 }
 
-pub fn part2(_: &str) -> eyre::Result<u32> {
+pub fn part2(_: &(&str, Mapping<'_>)) -> eyre::Result<u32> {
     Ok(0)
 }
 
-#[cfg(test)]
-mod tests {
-    use itertools::Itertools;
+pub struct Day;
 
-    use crate::next_molecules;
+impl aoc_util::Solution for Day {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 19;
+    const TITLE: &'static str = "Medicine for Rudolph";
+    const STUBS: &'static [aoc_util::Part] = &[aoc_util::Part::Two];
+
+    type Input<'a> = (&'a str, Mapping<'a>);
+    type Params = ();
+
+    fn parse(input: &str) -> eyre::Result<Self::Input<'_>> {
+        parse(input)
+    }
+    fn part1(input: &Self::Input<'_>, _: &Self::Params) -> eyre::Result<impl std::fmt::Display> {
+        part1(input)
+    }
+    fn part2(input: &Self::Input<'_>, _: &Self::Params) -> eyre::Result<impl std::fmt::Display> {
+        part2(input)
+    }
+}
+aoc_util::register!(Day);
 
-    const INPUT: &str = "H => HO
+aoc_util::examples! {
+    Day;
+    example: "H => HO
 H => OH
 O => HH
 
-HOH";
+HOH" => (4, _),
+}
 
-    #[test]
-    fn part1_works() -> eyre::Result<()> {
-        assert_eq!(super::part1(INPUT)?, 4);
-        Ok(())
-    }
-    #[test]
-    fn part2_works() -> eyre::Result<()> {
-        assert_eq!(super::part2(INPUT)?, 0);
-        Ok(())
-    }
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+
+    use crate::next_molecules;
 
     #[test]
     fn next_molecules_works() {
@@ -10,11 +10,11 @@ mod parse {
             .find_map(|(i, s)| input.starts_with(s).then_some((i + 1) as u32))
     }
     fn numeric_digit(input: &str) -> Option<u32> {
-        input.chars().nth(0).and_then(|c| c.to_digit(10))
+        input.chars().next().and_then(|c| c.to_digit(10))
     }
     pub fn calibration_digits<'a>(mut input: &'a str) -> impl Iterator<Item = u32> + 'a {
         std::iter::from_fn(move || {
-            while input.len() > 0 {
+            while !input.is_empty() {
                 let res = word_digit(input).or(numeric_digit(input));
                 if let Some(res) = res {
                     input = &input[1..];
@@ -28,57 +28,73 @@ mod parse {
     }
 }
 
-pub fn part1(input: &str) -> eyre::Result<u32> {
-    fn get_calibration_value<'a>(line: &'a str) -> u32 {
+pub fn parse(input: &str) -> eyre::Result<Vec<&str>> {
+    Ok(input.lines().collect())
+}
+
+pub fn part1(lines: &[&str]) -> eyre::Result<u32> {
+    fn get_calibration_value(line: &str) -> u32 {
         let mut itr = line.chars().filter_map(|c| c.to_digit(10));
         let tens = itr.next().unwrap_or_default();
-        let ones = itr.last().unwrap_or(tens);
+        let ones = itr.next_back().unwrap_or(tens);
 
         (tens * 10) + ones
     }
 
-    Ok(input.lines().map(get_calibration_value).sum())
+    Ok(lines.iter().map(|line| get_calibration_value(line)).sum())
 }
 
-pub fn part2(input: &str) -> eyre::Result<u32> {
-    Ok(input
-        .lines()
+pub fn part2(lines: &[&str]) -> eyre::Result<u32> {
+    Ok(lines
+        .iter()
         .map(|line| {
             let mut itr = parse::calibration_digits(line);
-            let first = itr.nth(0).unwrap();
+            let first = itr.next().unwrap();
             let last = itr.last().unwrap_or(first);
             first * 10 + last
         })
         .sum())
 }
 
-#[cfg(test)]
-mod tests {
-    #[test]
-    fn part1_works() -> eyre::Result<()> {
-        const INPUT: &str = "1abc2
-pqr3stu8vwx
-a1b2c3d4e5f
-treb7uchet";
+pub struct Day;
+
+impl aoc_util::Solution for Day {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 1;
+    const TITLE: &'static str = "Trebuchet?!";
 
-        assert_eq!(super::part1(INPUT)?, 142);
-        Ok(())
+    type Input<'a> = Vec<&'a str>;
+    type Params = ();
+
+    fn parse(input: &str) -> eyre::Result<Self::Input<'_>> {
+        parse(input)
+    }
+    fn part1(input: &Self::Input<'_>, _: &Self::Params) -> eyre::Result<impl std::fmt::Display> {
+        part1(input)
     }
+    fn part2(input: &Self::Input<'_>, _: &Self::Params) -> eyre::Result<impl std::fmt::Display> {
+        part2(input)
+    }
+}
+aoc_util::register!(Day);
 
-    #[test]
-    fn part2_works() -> eyre::Result<()> {
-        const INPUT: &str = "two1nine
+aoc_util::examples! {
+    Day;
+    example: "1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+treb7uchet" => part1 = 142,
+    words: "two1nine
 eightwothree
 abcone2threexyz
 xtwone3four
 4nineeightseven2
 zoneight234
-7pqrstsixteen";
-
-        assert_eq!(super::part2(INPUT)?, 281);
-        Ok(())
-    }
+7pqrstsixteen" => part2 = 281,
+}
 
+#[cfg(test)]
+mod tests {
     #[test]
     fn parse_works() {
         const INPUT: &str = "twofour36";
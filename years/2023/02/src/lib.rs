@@ -69,7 +69,7 @@ struct Revealed {
 }
 
 #[derive(Debug)]
-struct Game {
+pub struct Game {
     id: u32,
     revealed: Vec<Revealed>,
 }
@@ -85,7 +85,7 @@ impl Game {
                 }
             }
         }
-        return true;
+        true
     }
 
     fn min_required_cubes(&self) -> Cubes {
@@ -186,7 +186,12 @@ mod parse {
     }
 }
 
-pub fn part1(input: &str) -> eyre::Result<u32> {
+pub fn parse(input: &str) -> eyre::Result<Vec<Game>> {
+    let (_, games) = parse::parse(input).map_err(|e| eyre::format_err!("{e}"))?;
+    Ok(games)
+}
+
+pub fn part1(games: &[Game]) -> eyre::Result<u32> {
     let cubes = vec![
         Cube {
             color: Color::Red,
@@ -201,16 +206,13 @@ pub fn part1(input: &str) -> eyre::Result<u32> {
             amount: 14,
         },
     ];
-    let (_, games) = parse::parse(input).expect("parse games should work");
-
     Ok(games
         .iter()
         .filter_map(|game| game.is_possible(&cubes).then_some(game.id))
         .sum())
 }
 
-pub fn part2(input: &str) -> eyre::Result<u32> {
-    let (_, games) = parse::parse(input).expect("parse games works");
+pub fn part2(games: &[Game]) -> eyre::Result<u32> {
     Ok(games
         .iter()
         .map(|g| g.min_required_cubes())
@@ -218,29 +220,45 @@ pub fn part2(input: &str) -> eyre::Result<u32> {
         .sum())
 }
 
+pub struct Day;
+
+impl aoc_util::Solution for Day {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 2;
+    const TITLE: &'static str = "Cube Conundrum";
+
+    type Input<'a> = Vec<Game>;
+    type Params = ();
+
+    fn parse(input: &str) -> eyre::Result<Self::Input<'_>> {
+        parse(input)
+    }
+    fn part1(input: &Self::Input<'_>, _: &Self::Params) -> eyre::Result<impl std::fmt::Display> {
+        part1(input)
+    }
+    fn part2(input: &Self::Input<'_>, _: &Self::Params) -> eyre::Result<impl std::fmt::Display> {
+        part2(input)
+    }
+}
+aoc_util::register!(Day);
+
 #[cfg(test)]
-mod tests {
-    const INPUT: &str = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+const INPUT: &str = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
 Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
 Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
 Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
 Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
 
-    #[test]
-    fn part1_works() -> eyre::Result<()> {
-        assert_eq!(super::part1(INPUT)?, 8);
-        Ok(())
-    }
-
-    #[test]
-    fn part2_works() -> eyre::Result<()> {
-        assert_eq!(super::part2(INPUT)?, 2286);
-        Ok(())
-    }
+aoc_util::examples! {
+    Day;
+    example: INPUT => (8, 2286),
+}
 
+#[cfg(test)]
+mod tests {
     #[test]
     fn parse_works() {
-        let games = super::parse::parse(INPUT);
+        let games = super::parse::parse(super::INPUT);
         assert!(games.is_ok());
 
         let (_, games) = games.unwrap();
@@ -17,7 +17,7 @@ mod parse {
         let mut symbols = vec![];
 
         loop {
-            if input.len() == 0 {
+            if input.is_empty() {
                 break;
             }
 
@@ -63,7 +63,7 @@ mod parse {
 }
 
 #[derive(Debug)]
-struct PartNumber {
+pub struct PartNumber {
     value: u32,
     row: usize,
     columns: RangeInclusive<usize>,
@@ -75,13 +75,13 @@ impl PartNumber {
         let col_range = symbol.column.saturating_sub(1)..=symbol.column + 1;
 
         row_range.contains(&self.row)
-            && (col_range.contains(&self.columns.start())
-                || col_range.contains(&self.columns.end()))
+            && (col_range.contains(self.columns.start())
+                || col_range.contains(self.columns.end()))
     }
 }
 
 #[derive(Debug)]
-struct Symbol {
+pub struct Symbol {
     value: char,
     row: usize,
     column: usize,
@@ -101,7 +101,7 @@ impl Symbol {
 }
 
 #[derive(Debug)]
-struct Schematic {
+pub struct Schematic {
     parts: Vec<PartNumber>,
     symbols: Vec<Symbol>,
 }
@@ -121,18 +121,42 @@ impl Schematic {
     }
 }
 
-pub fn part1(input: &str) -> eyre::Result<u32> {
-    let (_, schematic) = parse::parse(input).expect("parse works");
+pub fn parse(input: &str) -> eyre::Result<Schematic> {
+    let (_, schematic) = parse::parse(input).map_err(|e| eyre::format_err!("{e}"))?;
+    Ok(schematic)
+}
+
+pub fn part1(schematic: &Schematic) -> eyre::Result<u32> {
     Ok(schematic.valid_part_numbers().map(|p| p.value).sum())
 }
-pub fn part2(input: &str) -> eyre::Result<u32> {
-    let (_, schematic) = parse::parse(input).expect("parse works");
+pub fn part2(schematic: &Schematic) -> eyre::Result<u32> {
     Ok(schematic.gear_ratio_sum())
 }
 
+pub struct Day;
+
+impl aoc_util::Solution for Day {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 3;
+    const TITLE: &'static str = "Gear Ratios";
+
+    type Input<'a> = Schematic;
+    type Params = ();
+
+    fn parse(input: &str) -> eyre::Result<Self::Input<'_>> {
+        parse(input)
+    }
+    fn part1(input: &Self::Input<'_>, _: &Self::Params) -> eyre::Result<impl std::fmt::Display> {
+        part1(input)
+    }
+    fn part2(input: &Self::Input<'_>, _: &Self::Params) -> eyre::Result<impl std::fmt::Display> {
+        part2(input)
+    }
+}
+aoc_util::register!(Day);
+
 #[cfg(test)]
-mod tests {
-    const INPUT: &str = "467..114..
+const INPUT: &str = "467..114..
 ...*......
 ..35..633.
 ......#...
@@ -143,20 +167,16 @@ mod tests {
 ...$.*....
 .664.598..";
 
-    #[test]
-    fn part1_works() -> eyre::Result<()> {
-        assert_eq!(super::part1(INPUT)?, 4361);
-        Ok(())
-    }
-    #[test]
-    fn part2_works() -> eyre::Result<()> {
-        assert_eq!(super::part2(INPUT)?, 467835);
-        Ok(())
-    }
+aoc_util::examples! {
+    Day;
+    example: INPUT => (4361, 467835),
+}
 
+#[cfg(test)]
+mod tests {
     #[test]
     fn parse_works() {
-        let schematic = super::parse::parse(INPUT);
+        let schematic = super::parse::parse(super::INPUT);
         assert!(schematic.is_ok());
     }
 }
@@ -30,8 +30,8 @@ mod parse {
                 input,
                 Card {
                     id: id - 1,
-                    numbers: HashSet::from_iter(numbers.into_iter()),
-                    winning_numbers: HashSet::from_iter(winning_numbers.into_iter()),
+                    numbers: HashSet::from_iter(numbers),
+                    winning_numbers: HashSet::from_iter(winning_numbers),
                 },
             ))
         }
@@ -41,7 +41,7 @@ mod parse {
 }
 
 #[derive(Debug)]
-struct Card {
+pub struct Card {
     id: usize,
     numbers: HashSet<u32>,
     winning_numbers: HashSet<u32>,
@@ -61,15 +61,18 @@ impl Card {
     }
 }
 
-pub fn part1(input: &str) -> eyre::Result<u32> {
-    let (_, cards) = parse::parse(input).expect("parse cards works");
+pub fn parse(input: &str) -> eyre::Result<Vec<Card>> {
+    let (_, cards) = parse::parse(input).map_err(|e| eyre::format_err!("{e}"))?;
+    Ok(cards)
+}
+
+pub fn part1(cards: &[Card]) -> eyre::Result<u32> {
     Ok(cards.iter().map(|card| card.points()).sum())
 }
-pub fn part2(input: &str) -> eyre::Result<u32> {
-    let (_, cards) = parse::parse(input).expect("parse cards works");
+pub fn part2(cards: &[Card]) -> eyre::Result<u32> {
     let mut copies = vec![1u32; cards.len()];
 
-    for card in &cards {
+    for card in cards {
         let match_count = card.match_count();
         let start = card.id + 1;
         let end = (start + match_count).min(cards.len());
@@ -82,24 +85,34 @@ pub fn part2(input: &str) -> eyre::Result<u32> {
     Ok(copies.iter().sum())
 }
 
-#[cfg(test)]
-mod tests {
+pub struct Day;
+
+impl aoc_util::Solution for Day {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 4;
+    const TITLE: &'static str = "Scratchcards";
+
+    type Input<'a> = Vec<Card>;
+    type Params = ();
+
+    fn parse(input: &str) -> eyre::Result<Self::Input<'_>> {
+        parse(input)
+    }
+    fn part1(input: &Self::Input<'_>, _: &Self::Params) -> eyre::Result<impl std::fmt::Display> {
+        part1(input)
+    }
+    fn part2(input: &Self::Input<'_>, _: &Self::Params) -> eyre::Result<impl std::fmt::Display> {
+        part2(input)
+    }
+}
+aoc_util::register!(Day);
 
-    const INPUT: &str = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+aoc_util::examples! {
+    Day;
+    example: "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
 Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
 Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
 Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
 Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
-Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
-
-    #[test]
-    fn part1_works() -> eyre::Result<()> {
-        assert_eq!(super::part1(INPUT)?, 13);
-        Ok(())
-    }
-    #[test]
-    fn part2_works() -> eyre::Result<()> {
-        assert_eq!(super::part2(INPUT)?, 30);
-        Ok(())
-    }
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11" => (13, 30),
 }
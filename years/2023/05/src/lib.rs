@@ -50,7 +50,7 @@ mod parse {
 }
 
 #[derive(Debug)]
-struct Mapping {
+pub struct Mapping {
     source: Range<isize>,
     offset: isize,
 }
@@ -68,7 +68,7 @@ impl Mapping {
 }
 
 #[derive(Debug)]
-struct Almanac {
+pub struct Almanac {
     seeds: Vec<isize>,
     maps: Vec<Vec<Mapping>>,
 }
@@ -95,8 +95,12 @@ impl Almanac {
     }
 }
 
-pub fn part1(input: &str) -> eyre::Result<u32> {
-    let (_, almanac) = parse::parse(input).expect("parse works");
+pub fn parse(input: &str) -> eyre::Result<Almanac> {
+    let (_, almanac) = parse::parse(input).map_err(|e| eyre::format_err!("{e}"))?;
+    Ok(almanac)
+}
+
+pub fn part1(almanac: &Almanac) -> eyre::Result<u32> {
     Ok(almanac
         .seeds
         .iter()
@@ -105,9 +109,7 @@ pub fn part1(input: &str) -> eyre::Result<u32> {
         .unwrap() as u32)
 }
 
-pub fn part2(input: &str) -> eyre::Result<u64> {
-    let (_, almanac) = parse::parse(input).expect("parse works");
-
+pub fn part2(almanac: &Almanac) -> eyre::Result<u64> {
     let seeds = almanac.seed_ranges();
     let count: u64 = seeds.clone().into_iter().map(|r| r.count() as u64).sum();
 
@@ -120,54 +122,36 @@ pub fn part2(input: &str) -> eyre::Result<u64> {
         .unwrap() as u64)
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const INPUT: &str = "seeds: 79 14 55 13
-
-seed-to-soil map:
-50 98 2
-52 50 48
-
-soil-to-fertilizer map:
-0 15 37
-37 52 2
-39 0 15
-
-fertilizer-to-water map:
-49 53 8
-0 11 42
-42 0 7
-57 7 4
-
-water-to-light map:
-88 18 7
-18 25 70
+pub struct Day;
 
-light-to-temperature map:
-45 77 23
-81 45 19
-68 64 13
+impl aoc_util::Solution for Day {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 5;
+    const TITLE: &'static str = "If You Give A Seed A Fertilizer";
 
-temperature-to-humidity map:
-0 69 1
-1 0 69
+    type Input<'a> = Almanac;
+    type Params = ();
 
-humidity-to-location map:
-60 56 37
-56 93 4";
-
-    #[test]
-    fn part1_works() -> eyre::Result<()> {
-        assert_eq!(super::part1(INPUT)?, 35);
-        Ok(())
+    fn parse(input: &str) -> eyre::Result<Self::Input<'_>> {
+        parse(input)
     }
-    #[test]
-    fn part2_works() -> eyre::Result<()> {
-        assert_eq!(super::part2(INPUT)?, 46);
-        Ok(())
+    fn part1(input: &Self::Input<'_>, _: &Self::Params) -> eyre::Result<impl std::fmt::Display> {
+        part1(input)
     }
+    fn part2(input: &Self::Input<'_>, _: &Self::Params) -> eyre::Result<impl std::fmt::Display> {
+        part2(input)
+    }
+}
+aoc_util::register!(Day);
+
+aoc_util::examples! {
+    Day;
+    example: file "examples/01.txt" => (35, 46),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
 
     #[test]
     fn map_works() {
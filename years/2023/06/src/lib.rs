@@ -2,7 +2,7 @@ mod parse {
     use aoc_util::parse::nom::{
         IResult, Parser,
         bytes::complete::tag,
-        character::complete::{digit1, newline, space1, u64},
+        character::complete::{newline, space1, u64},
         multi::separated_list1,
         sequence::{preceded, separated_pair},
     };
@@ -19,29 +19,12 @@ mod parse {
 
         let races = times
             .into_iter()
-            .zip(distances.into_iter())
+            .zip(distances)
             .map(|(time, distance)| Race { time, distance })
             .collect();
 
         Ok((input, races))
     }
-
-    pub fn parse_single(input: &str) -> IResult<&str, Race> {
-        let (_, (times, distances)) = separated_pair(
-            preceded((tag("Time:"), space1), separated_list1(space1, digit1)),
-            newline,
-            preceded((tag("Distance:"), space1), separated_list1(space1, digit1)),
-        )
-        .parse(input)?;
-
-        let time = times.join("").parse::<u64>().expect("times should join");
-        let distance = distances
-            .join("")
-            .parse::<u64>()
-            .expect("distances should join");
-
-        Ok((input, Race { time, distance }))
-    }
 }
 
 fn total_distance(max_time: u64, button_held: u64) -> u64 {
@@ -50,7 +33,7 @@ fn total_distance(max_time: u64, button_held: u64) -> u64 {
 }
 
 #[derive(Debug)]
-struct Race {
+pub struct Race {
     time: u64,
     distance: u64,
 }
@@ -63,31 +46,57 @@ impl Race {
     }
 }
 
-pub fn part1(input: &str) -> eyre::Result<u64> {
-    let (_, races) = parse::parse(input).expect("parse works");
+pub fn parse(input: &str) -> eyre::Result<Vec<Race>> {
+    let (_, races) = parse::parse(input).map_err(|e| eyre::format_err!("{e}"))?;
+    Ok(races)
+}
+
+pub fn part1(races: &[Race]) -> eyre::Result<u64> {
     Ok(races
         .iter()
         .map(|r| r.winning_times().count() as u64)
         .product())
 }
-pub fn part2(input: &str) -> eyre::Result<u64> {
-    let (_, race) = parse::parse_single(input).expect("parse works");
+/// The spaces between the numbers were bad kerning, the sheet describes a single race
+pub fn part2(races: &[Race]) -> eyre::Result<u64> {
+    let join = |value: fn(&Race) -> u64| {
+        races
+            .iter()
+            .map(|r| value(r).to_string())
+            .collect::<String>()
+            .parse::<u64>()
+    };
+    let race = Race {
+        time: join(|r| r.time)?,
+        distance: join(|r| r.distance)?,
+    };
     Ok(race.winning_times().count() as u64)
 }
 
-#[cfg(test)]
-mod tests {
-    const INPUT: &str = "Time:      7  15   30
-Distance:  9  40  200";
+pub struct Day;
+
+impl aoc_util::Solution for Day {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 6;
+    const TITLE: &'static str = "Wait For It";
 
-    #[test]
-    fn part1_works() -> eyre::Result<()> {
-        assert_eq!(super::part1(INPUT)?, 288);
-        Ok(())
+    type Input<'a> = Vec<Race>;
+    type Params = ();
+
+    fn parse(input: &str) -> eyre::Result<Self::Input<'_>> {
+        parse(input)
+    }
+    fn part1(input: &Self::Input<'_>, _: &Self::Params) -> eyre::Result<impl std::fmt::Display> {
+        part1(input)
     }
-    #[test]
-    fn part2_works() -> eyre::Result<()> {
-        assert_eq!(super::part2(INPUT)?, 71503);
-        Ok(())
+    fn part2(input: &Self::Input<'_>, _: &Self::Params) -> eyre::Result<impl std::fmt::Display> {
+        part2(input)
     }
 }
+aoc_util::register!(Day);
+
+aoc_util::examples! {
+    Day;
+    example: "Time:      7  15   30
+Distance:  9  40  200" => (288, 71503),
+}
@@ -42,7 +42,7 @@ mod parse {
 }
 
 #[derive(Copy, Clone, Default, PartialEq, Eq)]
-struct Card {
+pub struct Card {
     label: char,
     rank: usize,
 }
@@ -72,7 +72,7 @@ impl Card {
 }
 
 #[derive(PartialEq, Eq)]
-struct Hand {
+pub struct Hand {
     cards: [Card; 5],
 
     distinct_labels: usize,
@@ -127,8 +127,14 @@ impl std::fmt::Debug for Hand {
     }
 }
 
-pub fn part1(input: &str) -> eyre::Result<u32> {
-    let (_, mut hands) = parse::parse(input).expect("parse works");
+/// Every hand with its bid
+pub fn parse(input: &str) -> eyre::Result<Vec<(Hand, u32)>> {
+    let (_, hands) = parse::parse(input).map_err(|e| eyre::format_err!("{e}"))?;
+    Ok(hands)
+}
+
+pub fn part1(hands: &[(Hand, u32)]) -> eyre::Result<u32> {
+    let mut hands: Vec<_> = hands.iter().collect();
     hands.sort_by(|a, b| a.0.cmp(&b.0));
     Ok(hands
         .iter()
@@ -137,30 +143,45 @@ pub fn part1(input: &str) -> eyre::Result<u32> {
         .sum())
 }
 
-pub fn part2(_input: &str) -> eyre::Result<u32> {
+pub fn part2(_hands: &[(Hand, u32)]) -> eyre::Result<u32> {
     Ok(0)
 }
 
-#[cfg(test)]
-mod tests {
-    use crate::Hand;
+pub struct Day;
+
+impl aoc_util::Solution for Day {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 7;
+    const TITLE: &'static str = "Camel Cards";
+    const STUBS: &'static [aoc_util::Part] = &[aoc_util::Part::Two];
+
+    type Input<'a> = Vec<(Hand, u32)>;
+    type Params = ();
+
+    fn parse(input: &str) -> eyre::Result<Self::Input<'_>> {
+        parse(input)
+    }
+    fn part1(input: &Self::Input<'_>, _: &Self::Params) -> eyre::Result<impl std::fmt::Display> {
+        part1(input)
+    }
+    fn part2(input: &Self::Input<'_>, _: &Self::Params) -> eyre::Result<impl std::fmt::Display> {
+        part2(input)
+    }
+}
+aoc_util::register!(Day);
 
-    const INPUT: &str = "32T3K 765
+aoc_util::examples! {
+    Day;
+    example: "32T3K 765
 T55J5 684
 KK677 28
 KTJJT 220
-QQQJA 483";
+QQQJA 483" => (6440, _),
+}
 
-    #[test]
-    fn part1_works() -> eyre::Result<()> {
-        assert_eq!(super::part1(INPUT)?, 6440);
-        Ok(())
-    }
-    #[test]
-    fn part2_works() -> eyre::Result<()> {
-        assert_eq!(super::part2(INPUT)?, 0);
-        Ok(())
-    }
+#[cfg(test)]
+mod tests {
+    use crate::Hand;
 
     #[test]
     fn hand_tests() {
@@ -60,12 +60,12 @@ const END: &str = "ZZZ";
 
 #[repr(u8)]
 #[derive(Debug, Clone, Copy)]
-enum Direction {
+pub enum Direction {
     Left,
     Right = 1,
 }
 
-struct Map<'a>(HashMap<&'a str, [&'a str; 2]>);
+pub struct Map<'a>(HashMap<&'a str, [&'a str; 2]>);
 impl Map<'_> {
     fn path_length(
         &self,
@@ -103,7 +103,6 @@ impl Map<'_> {
                     None
                 }
             })
-            .inspect(|p| println!("{p}"))
             .product()
     }
 
@@ -138,21 +137,43 @@ impl Map<'_> {
     }
 }
 
-pub fn part1(input: &str) -> eyre::Result<u32> {
-    let (_, (map, directions)) = parse::parse(input).expect("parse works");
-    Ok(map.path_length(START, &directions, |s| s == END) as u32)
+pub fn parse(input: &str) -> eyre::Result<(Map<'_>, Vec<Direction>)> {
+    let (_, network) = parse::parse(input).map_err(|e| eyre::format_err!("{e}"))?;
+    Ok(network)
 }
-pub fn part2(input: &str) -> eyre::Result<u32> {
-    let (_, (map, directions)) = parse::parse(input).expect("parse works");
-    Ok(map.follow_paths_2(&directions) as u32)
+
+pub fn part1((map, directions): &(Map<'_>, Vec<Direction>)) -> eyre::Result<u32> {
+    Ok(map.path_length(START, directions, |s| s == END) as u32)
+}
+pub fn part2((map, directions): &(Map<'_>, Vec<Direction>)) -> eyre::Result<u32> {
+    Ok(map.follow_paths_2(directions) as u32)
 }
 
-#[cfg(test)]
-mod tests {
+pub struct Day;
+
+impl aoc_util::Solution for Day {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 8;
+    const TITLE: &'static str = "Haunted Wasteland";
+
+    type Input<'a> = (Map<'a>, Vec<Direction>);
+    type Params = ();
+
+    fn parse(input: &str) -> eyre::Result<Self::Input<'_>> {
+        parse(input)
+    }
+    fn part1(input: &Self::Input<'_>, _: &Self::Params) -> eyre::Result<impl std::fmt::Display> {
+        part1(input)
+    }
+    fn part2(input: &Self::Input<'_>, _: &Self::Params) -> eyre::Result<impl std::fmt::Display> {
+        part2(input)
+    }
+}
+aoc_util::register!(Day);
 
-    #[test]
-    fn part1_works() {
-        const INPUT_1: &str = "RL
+aoc_util::examples! {
+    Day;
+    example: "RL
 
 AAA = (BBB, CCC)
 BBB = (DDD, EEE)
@@ -160,20 +181,13 @@ CCC = (ZZZ, GGG)
 DDD = (DDD, DDD)
 EEE = (EEE, EEE)
 GGG = (GGG, GGG)
-ZZZ = (ZZZ, ZZZ)";
-
-        const INPUT_2: &str = "LLR
+ZZZ = (ZZZ, ZZZ)" => part1 = 2,
+    repeating: "LLR
 
 AAA = (BBB, BBB)
 BBB = (AAA, ZZZ)
-ZZZ = (ZZZ, ZZZ)";
-
-        assert_eq!(super::part1(INPUT_1).unwrap(), 2);
-        assert_eq!(super::part1(INPUT_2).unwrap(), 6);
-    }
-    #[test]
-    fn part2_works() {
-        const INPUT: &str = "LR
+ZZZ = (ZZZ, ZZZ)" => part1 = 6,
+    ghosts: "LR
 
 11A = (11B, XXX)
 11B = (XXX, 11Z)
@@ -182,8 +196,5 @@ ZZZ = (ZZZ, ZZZ)";
 22B = (22C, 22C)
 22C = (22Z, 22Z)
 22Z = (22B, 22B)
-XXX = (XXX, XXX)";
-
-        assert_eq!(super::part2(INPUT).unwrap(), 6);
-    }
+XXX = (XXX, XXX)" => part2 = 6,
 }
@@ -1,7 +0,0 @@
-const INPUT: &str = include_str!("../input.txt");
-
-fn main() -> eyre::Result<()> {
-    println!("{}", aoc2023day08::part1(INPUT)?);
-    println!("{}", aoc2023day08::part2(INPUT)?);
-    Ok(())
-}
@@ -1,14 +1,12 @@
-mod parse {
-    pub fn parse(input: &str) -> Vec<Vec<i64>> {
-        input
-            .lines()
-            .map(|line| {
-                line.split_whitespace()
-                    .map(|s| str::parse::<i64>(s).expect("integer values"))
-                    .collect::<Vec<_>>()
-            })
-            .collect()
-    }
+pub fn parse(input: &str) -> eyre::Result<Vec<Vec<i64>>> {
+    input
+        .lines()
+        .map(|line| {
+            line.split_whitespace()
+                .map(|s| Ok(str::parse::<i64>(s)?))
+                .collect::<eyre::Result<Vec<_>>>()
+        })
+        .collect()
 }
 
 fn diffs(itr: &[i64]) -> Vec<i64> {
@@ -35,46 +33,50 @@ fn prev(itr: &[i64]) -> i64 {
     itr.first().unwrap() - prev_diff
 }
 
-pub fn part1(input: &str) -> eyre::Result<i64> {
-    let sequences = parse::parse(input);
-
+pub fn part1(sequences: &[Vec<i64>]) -> eyre::Result<i64> {
     let mut sum = 0;
     for sequence in sequences {
-        sum += next(&sequence);
+        sum += next(sequence);
     }
 
     Ok(sum)
 }
 
-pub fn part2(input: &str) -> eyre::Result<i64> {
-    let sequences = parse::parse(input);
-
+pub fn part2(sequences: &[Vec<i64>]) -> eyre::Result<i64> {
     let mut sum = 0;
     for sequence in sequences {
-        sum += prev(&sequence);
+        sum += prev(sequence);
     }
 
     Ok(sum)
 }
 
-#[cfg(test)]
-mod tests {
-    const INPUT: &str = "0 3 6 9 12 15
-1 3 6 10 15 21
-10 13 16 21 30 45";
+pub struct Day;
+
+impl aoc_util::Solution for Day {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 9;
+    const TITLE: &'static str = "Mirage Maintenance";
 
-    const INPUT_2: &str =
-        "9 5 1 -3 -7 -11 -15 -19 -23 -27 -31 -35 -39 -43 -47 -51 -55 -59 -63 -67 -71";
+    type Input<'a> = Vec<Vec<i64>>;
+    type Params = ();
 
-    #[test]
-    fn part1_works() -> eyre::Result<()> {
-        assert_eq!(super::part1(INPUT)?, 114);
-        assert_eq!(super::part1(INPUT_2)?, -75);
-        Ok(())
+    fn parse(input: &str) -> eyre::Result<Self::Input<'_>> {
+        parse(input)
     }
-    #[test]
-    fn part2_works() -> eyre::Result<()> {
-        assert_eq!(super::part2(INPUT)?, 2);
-        Ok(())
+    fn part1(input: &Self::Input<'_>, _: &Self::Params) -> eyre::Result<impl std::fmt::Display> {
+        part1(input)
     }
+    fn part2(input: &Self::Input<'_>, _: &Self::Params) -> eyre::Result<impl std::fmt::Display> {
+        part2(input)
+    }
+}
+aoc_util::register!(Day);
+
+aoc_util::examples! {
+    Day;
+    example: "0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45" => (114, 2),
+    falling: "9 5 1 -3 -7 -11 -15 -19 -23 -27 -31 -35 -39 -43 -47 -51 -55 -59 -63 -67 -71" => part1 = -75,
 }
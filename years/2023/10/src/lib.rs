@@ -37,7 +37,7 @@ mod parse {
     }
 }
 
-struct Map {
+pub struct Map {
     positions: Vec<Vec<Tile>>,
 }
 
@@ -52,7 +52,7 @@ impl Debug for Map {
                 };
                 write!(f, "{}", c)?;
             }
-            write!(f, "\n")?;
+            writeln!(f)?;
         }
 
         Ok(())
@@ -173,30 +173,46 @@ enum Direction {
     West,
 }
 
-pub fn part1(input: &str) -> eyre::Result<u32> {
-    let (_, _) = Map::parse(input).expect("should parse the map");
+pub fn parse(input: &str) -> eyre::Result<Map> {
+    let (_, map) = Map::parse(input).map_err(|e| eyre::format_err!("{e}"))?;
+    Ok(map)
+}
+
+pub fn part1(_map: &Map) -> eyre::Result<u32> {
     Ok(0)
 }
-pub fn part2(_input: &str) -> eyre::Result<u32> {
+pub fn part2(_map: &Map) -> eyre::Result<u32> {
     Ok(0)
 }
 
-#[cfg(test)]
-mod tests {
-    const INPUT: &str = "..F7.
-.FJ|.
-SJ.L7
-|F--J
-LJ...";
+pub struct Day;
 
-    #[test]
-    fn part1_works() -> eyre::Result<()> {
-        assert_eq!(super::part1(INPUT)?, 0);
-        Ok(())
+impl aoc_util::Solution for Day {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 10;
+    const TITLE: &'static str = "Pipe Maze";
+    const STUBS: &'static [aoc_util::Part] = &aoc_util::Part::ALL;
+
+    type Input<'a> = Map;
+    type Params = ();
+
+    fn parse(input: &str) -> eyre::Result<Self::Input<'_>> {
+        parse(input)
     }
-    #[test]
-    fn part2_works() -> eyre::Result<()> {
-        assert_eq!(super::part2(INPUT)?, 0);
-        Ok(())
+    fn part1(input: &Self::Input<'_>, _: &Self::Params) -> eyre::Result<impl std::fmt::Display> {
+        part1(input)
+    }
+    fn part2(input: &Self::Input<'_>, _: &Self::Params) -> eyre::Result<impl std::fmt::Display> {
+        part2(input)
     }
 }
+aoc_util::register!(Day);
+
+aoc_util::examples! {
+    Day;
+    example: "..F7.
+.FJ|.
+SJ.L7
+|F--J
+LJ..." => (_, _),
+}
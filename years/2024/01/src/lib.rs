@@ -1,24 +1,26 @@
 use itertools::Itertools;
 
-fn parse_lists(input: &str) -> (Vec<i32>, Vec<i32>) {
+pub fn parse(input: &str) -> eyre::Result<(Vec<i32>, Vec<i32>)> {
     let mut first = vec![];
     let mut second = vec![];
 
     for line in input.lines() {
-        let (left, right) = line.split_once("   ").unwrap();
+        let (left, right) = line
+            .split_once("   ")
+            .ok_or_else(|| eyre::format_err!("no pair of locations in {line:?}"))?;
 
-        let left: i32 = left.parse().unwrap();
+        let left: i32 = left.parse()?;
         first.push(left);
 
-        let right: i32 = right.parse().unwrap();
+        let right: i32 = right.parse()?;
         second.push(right);
     }
 
-    (first, second)
+    Ok((first, second))
 }
 
-pub fn part1(input: &str) -> eyre::Result<i32> {
-    let (mut first, mut second) = parse_lists(input);
+pub fn part1((first, second): &(Vec<i32>, Vec<i32>)) -> eyre::Result<i32> {
+    let (mut first, mut second) = (first.clone(), second.clone());
     first.sort();
     second.sort();
 
@@ -28,32 +30,42 @@ pub fn part1(input: &str) -> eyre::Result<i32> {
         .map(|(a, b)| (a - b).abs())
         .sum::<i32>())
 }
-pub fn part2(input: &str) -> eyre::Result<i32> {
-    let (first, second) = parse_lists(input);
+pub fn part2((first, second): &(Vec<i32>, Vec<i32>)) -> eyre::Result<i32> {
     Ok(first
         .iter()
         .cartesian_product(second)
-        .filter_map(|(a, b)| (a == &b).then(|| b))
+        .filter_map(|(a, b)| (a == b).then_some(b))
         .sum::<i32>())
 }
 
-#[cfg(test)]
-mod tests {
-    const INPUT: &str = r"3   4
+pub struct Day;
+
+impl aoc_util::Solution for Day {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 1;
+    const TITLE: &'static str = "Historian Hysteria";
+
+    type Input<'a> = (Vec<i32>, Vec<i32>);
+    type Params = ();
+
+    fn parse(input: &str) -> eyre::Result<Self::Input<'_>> {
+        parse(input)
+    }
+    fn part1(input: &Self::Input<'_>, _: &Self::Params) -> eyre::Result<impl std::fmt::Display> {
+        part1(input)
+    }
+    fn part2(input: &Self::Input<'_>, _: &Self::Params) -> eyre::Result<impl std::fmt::Display> {
+        part2(input)
+    }
+}
+aoc_util::register!(Day);
+
+aoc_util::examples! {
+    Day;
+    example: r"3   4
 4   3
 2   5
 1   3
 3   9
-3   3";
-
-    #[test]
-    fn part1_works() -> eyre::Result<()> {
-        assert_eq!(super::part1(INPUT)?, 11);
-        Ok(())
-    }
-    #[test]
-    fn part2_works() -> eyre::Result<()> {
-        assert_eq!(super::part2(INPUT)?, 31);
-        Ok(())
-    }
+3   3" => (11, 31),
 }
@@ -1,14 +1,14 @@
 use itertools::Itertools;
 use std::cmp::Ordering;
 
-type Reports = Vec<Vec<i32>>;
+pub type Reports = Vec<Vec<i32>>;
 
 fn is_safe<'a>(report: impl Iterator<Item = &'a i32>) -> bool {
     let mut gradient: Option<Ordering> = None;
 
     for (left, right) in report.tuple_windows() {
         let diff = (left - right).abs();
-        let ord = left.cmp(&right);
+        let ord = left.cmp(right);
 
         let safe = match ord {
             // equal is never safe
@@ -18,7 +18,7 @@ fn is_safe<'a>(report: impl Iterator<Item = &'a i32>) -> bool {
                 gradient.get_or_insert(ord);
 
                 // safe if diff is between 1 and 3 and gradient is the same
-                diff >= 1 && diff <= 3 && Some(ord) == gradient
+                (1..=3).contains(&diff) && Some(ord) == gradient
             }
         };
 
@@ -27,10 +27,10 @@ fn is_safe<'a>(report: impl Iterator<Item = &'a i32>) -> bool {
         }
     }
 
-    return true;
+    true
 }
 
-fn parse(input: &str) -> eyre::Result<Reports> {
+pub fn parse(input: &str) -> eyre::Result<Reports> {
     let mut reports = Reports::new();
 
     for line in input.lines() {
@@ -46,16 +46,13 @@ fn parse(input: &str) -> eyre::Result<Reports> {
     Ok(reports)
 }
 
-pub fn part1(input: &str) -> eyre::Result<usize> {
-    let reports = parse(input)?;
+pub fn part1(reports: &Reports) -> eyre::Result<usize> {
     Ok(reports
         .iter()
         .filter(|report| is_safe(report.iter()))
         .count())
 }
-pub fn part2(input: &str) -> eyre::Result<u32> {
-    let reports = parse(input)?;
-
+pub fn part2(reports: &Reports) -> eyre::Result<u32> {
     let mut total = 0;
     for report in reports {
         if is_safe(report.iter()) {
@@ -80,23 +77,34 @@ pub fn part2(input: &str) -> eyre::Result<u32> {
     Ok(total)
 }
 
-#[cfg(test)]
-mod tests {
-    const INPUT: &str = r"7 6 4 2 1
+pub struct Day;
+
+impl aoc_util::Solution for Day {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 2;
+    const TITLE: &'static str = "Red-Nosed Reports";
+
+    type Input<'a> = Reports;
+    type Params = ();
+
+    fn parse(input: &str) -> eyre::Result<Self::Input<'_>> {
+        parse(input)
+    }
+    fn part1(input: &Self::Input<'_>, _: &Self::Params) -> eyre::Result<impl std::fmt::Display> {
+        part1(input)
+    }
+    fn part2(input: &Self::Input<'_>, _: &Self::Params) -> eyre::Result<impl std::fmt::Display> {
+        part2(input)
+    }
+}
+aoc_util::register!(Day);
+
+aoc_util::examples! {
+    Day;
+    example: r"7 6 4 2 1
 1 2 7 8 9
 9 7 6 2 1
 1 3 2 4 5
 8 6 4 4 1
-1 3 6 7 9";
-
-    #[test]
-    fn part1_works() -> eyre::Result<()> {
-        assert_eq!(super::part1(INPUT)?, 2);
-        Ok(())
-    }
-    #[test]
-    fn part2_works() -> eyre::Result<()> {
-        assert_eq!(super::part2(INPUT)?, 4);
-        Ok(())
-    }
+1 3 6 7 9" => (2, 4),
 }
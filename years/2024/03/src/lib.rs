@@ -1,13 +1,13 @@
 #[derive(Debug)]
-enum Expression {
+pub enum Expression {
     Do,
     Dont,
     Mul(usize, usize),
 }
 
-type Expressions = Vec<Expression>;
+pub type Expressions = Vec<Expression>;
 
-fn parse(input: &str) -> eyre::Result<Expressions> {
+pub fn parse(input: &str) -> eyre::Result<Expressions> {
     let regex = regex::RegexBuilder::new(r"mul\((\d+,\d+)\)|(don't\(\))|(do\(\))").build()?;
     let mut out = Expressions::new();
     for (_, [a]) in regex.captures_iter(input).map(|c| c.extract()) {
@@ -26,16 +26,13 @@ fn parse(input: &str) -> eyre::Result<Expressions> {
     Ok(out)
 }
 
-pub fn part1(input: &str) -> eyre::Result<usize> {
-    let expressions = parse(input)?;
+pub fn part1(expressions: &Expressions) -> eyre::Result<usize> {
     Ok(expressions.iter().fold(0, |acc, current| match current {
         Expression::Mul(a, b) => acc + a * b,
         _ => acc,
     }))
 }
-pub fn part2(input: &str) -> eyre::Result<usize> {
-    let expressions = parse(input)?;
-
+pub fn part2(expressions: &Expressions) -> eyre::Result<usize> {
     let mut enabled = true;
 
     Ok(expressions.iter().fold(0, |acc, current| match current {
@@ -52,20 +49,30 @@ pub fn part2(input: &str) -> eyre::Result<usize> {
     }))
 }
 
-#[cfg(test)]
-mod tests {
-    #[test]
-    fn part1_works() -> eyre::Result<()> {
-        const INPUT: &str =
-            "xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))";
-        assert_eq!(super::part1(INPUT)?, 161);
-        Ok(())
+pub struct Day;
+
+impl aoc_util::Solution for Day {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 3;
+    const TITLE: &'static str = "Mull It Over";
+
+    type Input<'a> = Expressions;
+    type Params = ();
+
+    fn parse(input: &str) -> eyre::Result<Self::Input<'_>> {
+        parse(input)
     }
-    #[test]
-    fn part2_works() -> eyre::Result<()> {
-        const INPUT: &str =
-            "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
-        assert_eq!(super::part2(INPUT)?, 48);
-        Ok(())
+    fn part1(input: &Self::Input<'_>, _: &Self::Params) -> eyre::Result<impl std::fmt::Display> {
+        part1(input)
     }
+    fn part2(input: &Self::Input<'_>, _: &Self::Params) -> eyre::Result<impl std::fmt::Display> {
+        part2(input)
+    }
+}
+aoc_util::register!(Day);
+
+aoc_util::examples! {
+    Day;
+    example: "xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))" => part1 = 161,
+    second: "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))" => part2 = 48,
 }
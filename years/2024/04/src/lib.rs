@@ -1,5 +1,8 @@
-pub fn part1(input: &str) -> eyre::Result<usize> {
-    let lines: Vec<_> = input.lines().collect();
+pub fn parse(input: &str) -> eyre::Result<Vec<&str>> {
+    Ok(input.lines().collect())
+}
+
+pub fn part1(lines: &[&str]) -> eyre::Result<usize> {
     const XMAS: [char; 4] = ['X', 'M', 'A', 'S'];
     const DIRECTIONS: [[(isize, isize); 4]; 8] = [
         [(0, 0), (0, 1), (0, 2), (0, 3)],       // RIGHT
@@ -12,10 +15,9 @@ pub fn part1(input: &str) -> eyre::Result<usize> {
         [(0, 0), (-1, -1), (-2, -2), (-3, -3)], // DOWN RIGHT
     ];
 
-    Ok(search(&lines, DIRECTIONS, XMAS))
+    Ok(search(lines, DIRECTIONS, XMAS))
 }
-pub fn part2(input: &str) -> eyre::Result<usize> {
-    let lines: Vec<_> = input.lines().collect();
+pub fn part2(lines: &[&str]) -> eyre::Result<usize> {
     const CROSS: [char; 5] = ['A', 'M', 'M', 'S', 'S'];
     const DIRECTIONS: [[(isize, isize); 5]; 4] = [
         [(0, 0), (1, 1), (1, -1), (-1, -1), (-1, 1)],
@@ -23,7 +25,7 @@ pub fn part2(input: &str) -> eyre::Result<usize> {
         [(0, 0), (-1, -1), (-1, 1), (1, 1), (1, -1)],
         [(0, 0), (-1, 1), (1, 1), (1, -1), (-1, -1)],
     ];
-    Ok(search(&lines, DIRECTIONS, CROSS))
+    Ok(search(lines, DIRECTIONS, CROSS))
 }
 
 fn search<const C: usize, const N: usize>(
@@ -47,7 +49,7 @@ fn search<const C: usize, const N: usize>(
                         continue 'search;
                     };
 
-                    if char != find[i as usize] {
+                    if char != find[i] {
                         continue 'search;
                     }
                 }
@@ -60,12 +62,31 @@ fn search<const C: usize, const N: usize>(
     total
 }
 
-#[cfg(test)]
-mod tests {
+pub struct Day;
+
+impl aoc_util::Solution for Day {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 4;
+    const TITLE: &'static str = "Ceres Search";
+
+    type Input<'a> = Vec<&'a str>;
+    type Params = ();
 
-    #[test]
-    fn part1_works() -> eyre::Result<()> {
-        const INPUT: &str = r"MMMSXXMASM
+    fn parse(input: &str) -> eyre::Result<Self::Input<'_>> {
+        parse(input)
+    }
+    fn part1(input: &Self::Input<'_>, _: &Self::Params) -> eyre::Result<impl std::fmt::Display> {
+        part1(input)
+    }
+    fn part2(input: &Self::Input<'_>, _: &Self::Params) -> eyre::Result<impl std::fmt::Display> {
+        part2(input)
+    }
+}
+aoc_util::register!(Day);
+
+aoc_util::examples! {
+    Day;
+    example: r"MMMSXXMASM
 MSAMXMSMSA
 AMXSXMAAMM
 MSAMASMSMX
@@ -74,14 +95,8 @@ XXAMMXXAMA
 SMSMSASXSS
 SAXAMASAAA
 MAMMMXMMMM
-MXMXAXMASX";
-
-        assert_eq!(super::part1(INPUT)?, 18);
-        Ok(())
-    }
-    #[test]
-    fn part2_works() -> eyre::Result<()> {
-        const INPUT: &str = r".M.S......
+MXMXAXMASX" => part1 = 18,
+    second: r".M.S......
 ..A..MSMS.
 .M.S.MAA..
 ..A.ASMSM.
@@ -90,9 +105,5 @@ MXMXAXMASX";
 S.S.S.S.S.
 .A.A.A.A..
 M.M.M.M.M.
-..........";
-
-        assert_eq!(super::part2(INPUT)?, 9);
-        Ok(())
-    }
+.........." => part2 = 9,
 }
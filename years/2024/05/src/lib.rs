@@ -11,7 +11,7 @@ use aoc_util::parse::nom::{
 
 // map of page numbers -> numbers that should NOT predcede them
 #[derive(Debug, derive_more::Deref, derive_more::DerefMut, PartialEq, Eq)]
-struct Rules(std::collections::HashMap<u32, HashSet<u32>>);
+pub struct Rules(std::collections::HashMap<u32, HashSet<u32>>);
 
 impl FromIterator<(u32, u32)> for Rules {
     fn from_iter<T: IntoIterator<Item = (u32, u32)>>(iter: T) -> Self {
@@ -27,9 +27,9 @@ impl FromIterator<(u32, u32)> for Rules {
     }
 }
 
-type Updates = Vec<Vec<u32>>;
+pub type Updates = Vec<Vec<u32>>;
 
-fn parse(input: &str) -> IResult<&str, (Rules, Updates)> {
+fn manual(input: &str) -> IResult<&str, (Rules, Updates)> {
     use aoc_util::parse::parse_num;
 
     separated_pair(
@@ -43,26 +43,27 @@ fn parse(input: &str) -> IResult<&str, (Rules, Updates)> {
     .parse(input)
 }
 
-pub fn part1(input: &'static str) -> eyre::Result<u32> {
-    let (_, (rules, updates)) = parse(input)?;
+pub fn parse(input: &str) -> eyre::Result<(Rules, Updates)> {
+    let (_, manual) = manual(input).map_err(|e| eyre::format_err!("parse error: {e}"))?;
+    Ok(manual)
+}
 
+pub fn part1((rules, updates): &(Rules, Updates)) -> eyre::Result<u32> {
     Ok(updates
         .iter()
-        .filter(|update| is_correct(update, &rules))
+        .filter(|update| is_correct(update, rules))
         .filter_map(|update| update.get(update.len() / 2))
         .sum())
 }
-pub fn part2(input: &'static str) -> eyre::Result<u32> {
-    let (_, (rules, mut updates)) = parse(input)?;
-
+pub fn part2((rules, updates): &(Rules, Updates)) -> eyre::Result<u32> {
+    let mut updates = updates.clone();
     Ok(updates
         .iter_mut()
-        .filter(|update| !is_correct(update, &rules))
-        .map(|update| {
-            fix(update, &rules);
-            update
+        .filter(|update| !is_correct(update, rules))
+        .filter_map(|update| {
+            fix(update, rules);
+            update.get(update.len() / 2).copied()
         })
-        .filter_map(|update| update.get(update.len() / 2))
         .sum())
 }
 
@@ -71,7 +72,7 @@ fn fix(update: &mut [u32], rules: &Rules) {
         for i in (0..update.len()).rev() {
             if let Some(rule) = rules.get(&update[i]) {
                 for p in (0..i).rev() {
-                    if rule.contains(&(update[p] as u32)) {
+                    if rule.contains(&update[p]) {
                         update.swap(i, p);
                         break;
                     }
@@ -97,9 +98,31 @@ fn is_correct(update: &[u32], rules: &Rules) -> bool {
     true
 }
 
-#[cfg(test)]
-mod tests {
-    const INPUT: &str = "47|53
+pub struct Day;
+
+impl aoc_util::Solution for Day {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 5;
+    const TITLE: &'static str = "Print Queue";
+
+    type Input<'a> = (Rules, Updates);
+    type Params = ();
+
+    fn parse(input: &str) -> eyre::Result<Self::Input<'_>> {
+        parse(input)
+    }
+    fn part1(input: &Self::Input<'_>, _: &Self::Params) -> eyre::Result<impl std::fmt::Display> {
+        part1(input)
+    }
+    fn part2(input: &Self::Input<'_>, _: &Self::Params) -> eyre::Result<impl std::fmt::Display> {
+        part2(input)
+    }
+}
+aoc_util::register!(Day);
+
+aoc_util::examples! {
+    Day;
+    example: "47|53
 97|13
 97|61
 97|47
@@ -126,16 +149,5 @@ mod tests {
 75,29,13
 75,97,47,61,53
 61,13,29
-97,13,75,29,47";
-
-    #[test]
-    fn part1_works() -> eyre::Result<()> {
-        assert_eq!(super::part1(INPUT)?, 143);
-        Ok(())
-    }
-    #[test]
-    fn part2_works() -> eyre::Result<()> {
-        assert_eq!(super::part2(INPUT)?, 123);
-        Ok(())
-    }
+97,13,75,29,47" => (143, 123),
 }
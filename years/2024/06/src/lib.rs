@@ -4,15 +4,18 @@ use std::{
     str::FromStr,
 };
 
-pub fn part1(input: &str) -> eyre::Result<usize> {
-    let grid = Grid::from_str(input)?;
+pub fn parse(input: &str) -> eyre::Result<Grid<char>> {
+    Ok(Grid::from_str(input)?)
+}
+
+pub fn part1(grid: &Grid<char>) -> eyre::Result<usize> {
     Ok(path_length(grid))
 }
-pub fn part2(_input: &str) -> eyre::Result<u32> {
+pub fn part2(_grid: &Grid<char>) -> eyre::Result<u32> {
     Ok(0)
 }
 
-fn path_length(input: Grid<char>) -> usize {
+fn path_length(input: &Grid<char>) -> usize {
     let mut unique = HashSet::new();
 
     // map from position to direction
@@ -49,9 +52,32 @@ fn path_length(input: Grid<char>) -> usize {
     unique.len()
 }
 
-#[cfg(test)]
-mod tests {
-    const INPUT: &str = r"....#.....
+pub struct Day;
+
+impl aoc_util::Solution for Day {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 6;
+    const TITLE: &'static str = "Guard Gallivant";
+    const STUBS: &'static [aoc_util::Part] = &[aoc_util::Part::Two];
+
+    type Input<'a> = Grid<char>;
+    type Params = ();
+
+    fn parse(input: &str) -> eyre::Result<Self::Input<'_>> {
+        parse(input)
+    }
+    fn part1(input: &Self::Input<'_>, _: &Self::Params) -> eyre::Result<impl std::fmt::Display> {
+        part1(input)
+    }
+    fn part2(input: &Self::Input<'_>, _: &Self::Params) -> eyre::Result<impl std::fmt::Display> {
+        part2(input)
+    }
+}
+aoc_util::register!(Day);
+
+aoc_util::examples! {
+    Day;
+    example: r"....#.....
 .........#
 ..........
 ..#.......
@@ -60,16 +86,5 @@ mod tests {
 .#..^.....
 ........#.
 #.........
-......#...";
-
-    #[test]
-    fn part1_works() -> eyre::Result<()> {
-        assert_eq!(super::part1(INPUT)?, 41);
-        Ok(())
-    }
-    #[test]
-    fn part2_works() -> eyre::Result<()> {
-        assert_eq!(super::part2(INPUT)?, 0);
-        Ok(())
-    }
+......#..." => (41, _),
 }
@@ -8,17 +8,19 @@ use aoc_util::parse::nom::{
 };
 use itertools::Itertools;
 
-pub fn part1(input: &'static str) -> eyre::Result<u64> {
-    let (_, equations) = parse(input)?;
+pub fn parse(input: &str) -> eyre::Result<Vec<Equation>> {
+    let (_, equations) = equations(input).map_err(|e| eyre::format_err!("parse error: {e}"))?;
+    Ok(equations)
+}
 
+pub fn part1(equations: &[Equation]) -> eyre::Result<u64> {
     Ok(equations
         .iter()
         .filter(|e| is_solvable(e, false))
         .map(|e| e.result)
         .sum())
 }
-pub fn part2(input: &'static str) -> eyre::Result<u64> {
-    let (_, equations) = parse(input)?;
+pub fn part2(equations: &[Equation]) -> eyre::Result<u64> {
     Ok(equations
         .iter()
         .filter(|e| is_solvable(e, true))
@@ -26,7 +28,7 @@ pub fn part2(input: &'static str) -> eyre::Result<u64> {
         .sum())
 }
 
-fn parse(input: &str) -> IResult<&str, Vec<Equation>> {
+fn equations(input: &str) -> IResult<&str, Vec<Equation>> {
     use aoc_util::parse::parse_num;
     separated_list1(
         newline,
@@ -39,7 +41,7 @@ fn parse(input: &str) -> IResult<&str, Vec<Equation>> {
 }
 
 #[derive(Debug, PartialEq, Eq)]
-struct Equation {
+pub struct Equation {
     result: u64,
     operands: Vec<u64>,
 }
@@ -86,9 +88,31 @@ fn is_solvable(equation: &Equation, include_concat: bool) -> bool {
         })
 }
 
-#[cfg(test)]
-mod tests {
-    const INPUT: &str = r"190: 10 19
+pub struct Day;
+
+impl aoc_util::Solution for Day {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 7;
+    const TITLE: &'static str = "Bridge Repair";
+
+    type Input<'a> = Vec<Equation>;
+    type Params = ();
+
+    fn parse(input: &str) -> eyre::Result<Self::Input<'_>> {
+        parse(input)
+    }
+    fn part1(input: &Self::Input<'_>, _: &Self::Params) -> eyre::Result<impl std::fmt::Display> {
+        part1(input)
+    }
+    fn part2(input: &Self::Input<'_>, _: &Self::Params) -> eyre::Result<impl std::fmt::Display> {
+        part2(input)
+    }
+}
+aoc_util::register!(Day);
+
+aoc_util::examples! {
+    Day;
+    example: r"190: 10 19
 3267: 81 40 27
 83: 17 5
 156: 15 6
@@ -96,16 +120,5 @@ mod tests {
 161011: 16 10 13
 192: 17 8 14
 21037: 9 7 18 13
-292: 11 6 16 20";
-
-    #[test]
-    fn part1_works() -> eyre::Result<()> {
-        assert_eq!(super::part1(INPUT)?, 3749);
-        Ok(())
-    }
-    #[test]
-    fn part2_works() -> eyre::Result<()> {
-        assert_eq!(super::part2(INPUT)?, 11387);
-        Ok(())
-    }
+292: 11 6 16 20" => (3749, 11387),
 }
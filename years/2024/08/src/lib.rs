@@ -2,12 +2,14 @@ use aoc_util::euclid;
 use itertools::Itertools;
 use std::collections::HashSet;
 
-pub fn part1(input: &str) -> eyre::Result<usize> {
-    let map = Map::new(input);
+pub fn parse(input: &str) -> eyre::Result<Map<'_>> {
+    Ok(Map::new(input))
+}
+
+pub fn part1(map: &Map<'_>) -> eyre::Result<usize> {
     Ok(map.antinodes(false).count())
 }
-pub fn part2(input: &str) -> eyre::Result<usize> {
-    let map = Map::new(input);
+pub fn part2(map: &Map<'_>) -> eyre::Result<usize> {
     Ok(map.antinodes(true).count())
 }
 
@@ -17,7 +19,7 @@ struct CoordPair(
     euclid::default::Vector2D<isize>,
 );
 
-struct Map<'a> {
+pub struct Map<'a> {
     lines: Vec<&'a str>,
     frequencies: HashSet<char>,
 }
@@ -26,8 +28,7 @@ impl<'a> Map<'a> {
         let lines = map.lines().collect_vec();
         let frequences = lines
             .iter()
-            .map(|l| l.chars())
-            .flatten()
+            .flat_map(|l| l.chars())
             .filter(|c| *c != '.')
             .collect();
         Self {
@@ -72,7 +73,7 @@ impl<'a> Map<'a> {
                 antennae
                     .clone()
                     .cartesian_product(antennae)
-                    .filter_map(|(a, b)| (a != b).then(|| CoordPair(a, b)))
+                    .filter_map(|(a, b)| (a != b).then_some(CoordPair(a, b)))
                     .flat_map(move |CoordPair(a, b): CoordPair| {
                         let diff = b - a;
 
@@ -127,10 +128,31 @@ impl std::fmt::Display for Map<'_> {
     }
 }
 
-#[cfg(test)]
-mod tests {
+pub struct Day;
+
+impl aoc_util::Solution for Day {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 8;
+    const TITLE: &'static str = "Resonant Collinearity";
+
+    type Input<'a> = Map<'a>;
+    type Params = ();
+
+    fn parse(input: &str) -> eyre::Result<Self::Input<'_>> {
+        parse(input)
+    }
+    fn part1(input: &Self::Input<'_>, _: &Self::Params) -> eyre::Result<impl std::fmt::Display> {
+        part1(input)
+    }
+    fn part2(input: &Self::Input<'_>, _: &Self::Params) -> eyre::Result<impl std::fmt::Display> {
+        part2(input)
+    }
+}
+aoc_util::register!(Day);
 
-    const INPUT: &str = r"............
+aoc_util::examples! {
+    Day;
+    example: r"............
 ........0...
 .....0......
 .......0....
@@ -141,16 +163,5 @@ mod tests {
 ........A...
 .........A..
 ............
-............";
-
-    #[test]
-    fn part1_works() -> eyre::Result<()> {
-        assert_eq!(super::part1(INPUT)?, 14);
-        Ok(())
-    }
-    #[test]
-    fn part2_works() -> eyre::Result<()> {
-        assert_eq!(super::part2(INPUT)?, 34);
-        Ok(())
-    }
+............" => (14, 34),
 }
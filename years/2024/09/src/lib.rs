@@ -1,5 +1,5 @@
-pub fn part1(input: &str) -> eyre::Result<usize> {
-    let mut disk = parse(input);
+pub fn part1(disk: &Disk) -> eyre::Result<usize> {
+    let mut disk = disk.clone();
 
     let mut left = 0;
     let mut right = disk.len() - 1;
@@ -29,13 +29,12 @@ pub fn part1(input: &str) -> eyre::Result<usize> {
 
     Ok(checksum(&disk))
 }
-pub fn part2(input: &str) -> eyre::Result<usize> {
-    let mut _disk = parse(input);
+pub fn part2(_disk: &Disk) -> eyre::Result<usize> {
     Ok(0)
 }
 
 #[derive(Clone, derive_more::Deref, derive_more::DerefMut)]
-struct Disk(Vec<Option<usize>>);
+pub struct Disk(Vec<Option<usize>>);
 
 fn checksum(disk: &Disk) -> usize {
     disk.iter()
@@ -45,7 +44,7 @@ fn checksum(disk: &Disk) -> usize {
         .sum()
 }
 
-fn parse(input: &str) -> Disk {
+pub fn parse(input: &str) -> eyre::Result<Disk> {
     let digits = input.chars().filter_map(|c| c.to_digit(10));
     let blocks: u32 = digits.clone().sum::<u32>();
 
@@ -70,21 +69,33 @@ fn parse(input: &str) -> Disk {
         idx += block_size;
     }
 
-    disk
+    Ok(disk)
 }
 
-#[cfg(test)]
-mod tests {
-    const INPUT: &str = "2333133121414131402";
+pub struct Day;
 
-    #[test]
-    fn part1_works() -> eyre::Result<()> {
-        assert_eq!(super::part1(INPUT)?, 1928);
-        Ok(())
+impl aoc_util::Solution for Day {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 9;
+    const TITLE: &'static str = "Disk Fragmenter";
+    const STUBS: &'static [aoc_util::Part] = &[aoc_util::Part::Two];
+
+    type Input<'a> = Disk;
+    type Params = ();
+
+    fn parse(input: &str) -> eyre::Result<Self::Input<'_>> {
+        parse(input)
+    }
+    fn part1(input: &Self::Input<'_>, _: &Self::Params) -> eyre::Result<impl std::fmt::Display> {
+        part1(input)
     }
-    #[test]
-    fn part2_works() -> eyre::Result<()> {
-        assert_eq!(super::part2(INPUT)?, 0);
-        Ok(())
+    fn part2(input: &Self::Input<'_>, _: &Self::Params) -> eyre::Result<impl std::fmt::Display> {
+        part2(input)
     }
 }
+aoc_util::register!(Day);
+
+aoc_util::examples! {
+    Day;
+    example: "2333133121414131402" => (1928, _),
+}
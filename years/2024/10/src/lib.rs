@@ -19,12 +19,9 @@ fn valid_directions<'a>(
 
     DIRECTIONS
         .iter()
-        .filter_map(move |dir| {
-            let next = height_at(grid, pos + *dir);
-            match next {
-                Some(n) if n.saturating_sub(current) == 1 => Some(dir),
-                _ => None,
-            }
+        .filter(move |dir| {
+            let next = height_at(grid, pos + **dir);
+            matches!(next, Some(n) if n.saturating_sub(current) == 1)
         })
         .copied()
 }
@@ -56,54 +53,67 @@ fn find_unique_trails(grid: &Grid<char>, from: Position) -> u32 {
             }
             total
         }
-        None => return 0,
+        None => 0,
     }
 }
 
-pub fn part1(input: &str) -> eyre::Result<u32> {
-    let grid = Grid::from_str(input)?;
+pub fn parse(input: &str) -> eyre::Result<Grid<char>> {
+    Ok(Grid::from_str(input)?)
+}
+
+pub fn part1(grid: &Grid<char>) -> eyre::Result<u32> {
     let start_positions = grid.find_all(&'0');
 
     let mut total = 0;
     for pos in start_positions {
         let mut found = HashSet::new();
-        find_unique_peaks(&grid, pos, &mut found);
+        find_unique_peaks(grid, pos, &mut found);
         total += found.len();
     }
 
     Ok(total as u32)
 }
-pub fn part2(input: &str) -> eyre::Result<u32> {
-    let grid = Grid::from_str(input)?;
+pub fn part2(grid: &Grid<char>) -> eyre::Result<u32> {
     let start_positions = grid.find_all(&'0');
 
     let mut total = 0;
     for pos in start_positions {
-        total += find_unique_trails(&grid, pos);
+        total += find_unique_trails(grid, pos);
     }
 
     Ok(total)
 }
 
-#[cfg(test)]
-mod tests {
-    const INPUT: &str = "89010123
+pub struct Day;
+
+impl aoc_util::Solution for Day {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 10;
+    const TITLE: &'static str = "Hoof It";
+
+    type Input<'a> = Grid<char>;
+    type Params = ();
+
+    fn parse(input: &str) -> eyre::Result<Self::Input<'_>> {
+        parse(input)
+    }
+    fn part1(input: &Self::Input<'_>, _: &Self::Params) -> eyre::Result<impl std::fmt::Display> {
+        part1(input)
+    }
+    fn part2(input: &Self::Input<'_>, _: &Self::Params) -> eyre::Result<impl std::fmt::Display> {
+        part2(input)
+    }
+}
+aoc_util::register!(Day);
+
+aoc_util::examples! {
+    Day;
+    example: "89010123
 78121874
 87430965
 96549874
 45678903
 32019012
 01329801
-10456732";
-
-    #[test]
-    fn part1_works() -> eyre::Result<()> {
-        assert_eq!(super::part1(INPUT)?, 36);
-        Ok(())
-    }
-    #[test]
-    fn part2_works() -> eyre::Result<()> {
-        assert_eq!(super::part2(INPUT)?, 81);
-        Ok(())
-    }
+10456732" => (36, 81),
 }
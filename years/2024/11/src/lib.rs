@@ -1,5 +1,3 @@
-#![feature(linked_list_cursors)]
-
 use std::collections::HashMap;
 
 use aoc_util::{
@@ -11,10 +9,15 @@ use aoc_util::{
     },
 };
 
-fn parse(input: &str) -> IResult<&str, Vec<u64>> {
+fn stones(input: &str) -> IResult<&str, Vec<u64>> {
     separated_list1(space1, u64).parse(input)
 }
 
+pub fn parse(input: &str) -> eyre::Result<Vec<u64>> {
+    let (_, stones) = stones(input).map_err(|e| eyre::format_err!("{e}"))?;
+    Ok(stones)
+}
+
 fn count_descendants(stone: u64, blinks: usize, cache: &mut HashMap<(u64, usize), usize>) -> usize {
     if blinks == 0 {
         return 1;
@@ -46,27 +49,36 @@ fn stare(stones: &[u64], blinks: usize) -> usize {
         .sum()
 }
 
-pub fn part1(input: &str) -> eyre::Result<usize> {
-    let (_, stones) = parse(input).expect("should parse");
-    Ok(stare(&stones, 25))
+pub fn part1(stones: &[u64]) -> eyre::Result<usize> {
+    Ok(stare(stones, 25))
 }
-pub fn part2(input: &str) -> eyre::Result<usize> {
-    let (_, stones) = parse(input).expect("should parse");
-    Ok(stare(&stones, 75))
+pub fn part2(stones: &[u64]) -> eyre::Result<usize> {
+    Ok(stare(stones, 75))
 }
 
-#[cfg(test)]
-mod tests {
-    const INPUT: &str = "125 17";
+pub struct Day;
+
+impl aoc_util::Solution for Day {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 11;
+    const TITLE: &'static str = "Plutonian Pebbles";
 
-    #[test]
-    fn part1_works() -> eyre::Result<()> {
-        assert_eq!(super::part1(INPUT)?, 55312);
-        Ok(())
+    type Input<'a> = Vec<u64>;
+    type Params = ();
+
+    fn parse(input: &str) -> eyre::Result<Self::Input<'_>> {
+        parse(input)
+    }
+    fn part1(input: &Self::Input<'_>, _: &Self::Params) -> eyre::Result<impl std::fmt::Display> {
+        part1(input)
     }
-    #[test]
-    fn part2_works() -> eyre::Result<()> {
-        assert_eq!(super::part2(INPUT)?, 65601038650482);
-        Ok(())
+    fn part2(input: &Self::Input<'_>, _: &Self::Params) -> eyre::Result<impl std::fmt::Display> {
+        part2(input)
     }
 }
+aoc_util::register!(Day);
+
+aoc_util::examples! {
+    Day;
+    example: "125 17" => (55312, 65601038650482u64),
+}
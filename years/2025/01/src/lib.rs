@@ -5,7 +5,7 @@ use aoc_util::parse::nom::{
     IResult, Parser,
 };
 
-fn parse(input: &str) -> IResult<&str, Vec<i16>> {
+fn turns(input: &str) -> IResult<&str, Vec<i16>> {
     separated_list1(
         newline,
         (
@@ -17,12 +17,15 @@ fn parse(input: &str) -> IResult<&str, Vec<i16>> {
     .parse(input)
 }
 
-pub fn part1(input: &str) -> eyre::Result<u32> {
-    let (_, turns) = parse(input).map_err(|e| eyre::format_err!("{e}"))?;
+pub fn parse(input: &str) -> eyre::Result<Vec<i16>> {
+    let (_, turns) = turns(input).map_err(|e| eyre::format_err!("{e}"))?;
+    Ok(turns)
+}
 
+pub fn part1(turns: &[i16]) -> eyre::Result<u32> {
     let mut dial = 50i16;
     let mut password = 0;
-    for turn in turns {
+    for &turn in turns {
         dial = (turn + dial).rem_euclid(100);
         if dial == 0 {
             password += 1;
@@ -31,13 +34,11 @@ pub fn part1(input: &str) -> eyre::Result<u32> {
 
     Ok(password)
 }
-pub fn part2(input: &str) -> eyre::Result<u32> {
-    let (_, turns) = parse(input).map_err(|e| eyre::format_err!("{e}"))?;
-
+pub fn part2(turns: &[i16]) -> eyre::Result<u32> {
     let mut dial = 50i16;
     let mut password = 0;
-    for turn in turns {
-        let full_turns = (turn / 100).abs() as u32;
+    for &turn in turns {
+        let full_turns = (turn / 100).unsigned_abs() as u32;
         password += full_turns;
 
         let next = dial + turn % 100;
@@ -51,9 +52,31 @@ pub fn part2(input: &str) -> eyre::Result<u32> {
     Ok(password)
 }
 
-#[cfg(test)]
-mod tests {
-    const INPUT: &str = "L68
+pub struct Day;
+
+impl aoc_util::Solution for Day {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 1;
+    const TITLE: &'static str = "Secret Entrance";
+
+    type Input<'a> = Vec<i16>;
+    type Params = ();
+
+    fn parse(input: &str) -> eyre::Result<Self::Input<'_>> {
+        parse(input)
+    }
+    fn part1(input: &Self::Input<'_>, _: &Self::Params) -> eyre::Result<impl std::fmt::Display> {
+        part1(input)
+    }
+    fn part2(input: &Self::Input<'_>, _: &Self::Params) -> eyre::Result<impl std::fmt::Display> {
+        part2(input)
+    }
+}
+aoc_util::register!(Day);
+
+aoc_util::examples! {
+    Day;
+    example: "L68
 L30
 R48
 L5
@@ -62,17 +85,13 @@ L55
 L1
 L99
 R14
-L82";
+L82" => (3, 6),
+}
 
+#[cfg(test)]
+mod tests {
     #[test]
-    fn part1_works() -> eyre::Result<()> {
+    fn rem_euclid_wraps_around() {
         assert_eq!((-1i32).rem_euclid(100), 99);
-        assert_eq!(super::part1(INPUT)?, 3);
-        Ok(())
-    }
-    #[test]
-    fn part2_works() -> eyre::Result<()> {
-        assert_eq!(super::part2(INPUT)?, 6);
-        Ok(())
     }
 }
@@ -10,7 +10,7 @@ use aoc_util::{
 use itertools::Itertools;
 use std::{num::NonZero, ops::RangeInclusive};
 
-fn parse(input: &str) -> IResult<&str, Vec<RangeInclusive<usize>>> {
+fn ranges(input: &str) -> IResult<&str, Vec<RangeInclusive<usize>>> {
     separated_list1(
         char(','),
         separated_pair(usize, char('-'), usize).map(|(a, b)| a..=b),
@@ -18,7 +18,12 @@ fn parse(input: &str) -> IResult<&str, Vec<RangeInclusive<usize>>> {
     .parse(input)
 }
 
-pub fn part1(input: &str) -> eyre::Result<usize> {
+pub fn parse(input: &str) -> eyre::Result<Vec<RangeInclusive<usize>>> {
+    let (_, ranges) = ranges(input).map_err(|e| eyre::format_err!("{e}"))?;
+    Ok(ranges)
+}
+
+pub fn part1(ranges: &[RangeInclusive<usize>]) -> eyre::Result<usize> {
     fn is_invalid_id(id: usize) -> bool {
         let num_digits = num_digits(id);
         if num_digits % 2 == 1 {
@@ -32,17 +37,17 @@ pub fn part1(input: &str) -> eyre::Result<usize> {
         }
     }
 
-    let (_, pairs) = parse(input).map_err(|e| eyre::format_err!("{e}"))?;
-    Ok(pairs
-        .into_iter()
-        .flat_map(|r| r)
+    Ok(ranges
+        .iter()
+        .cloned()
+        .flatten()
         .filter(|u| is_invalid_id(*u))
         .sum::<usize>())
 }
 
-pub fn part2(input: &str) -> eyre::Result<usize> {
+pub fn part2(ranges: &[RangeInclusive<usize>]) -> eyre::Result<usize> {
     fn digit_chunks(mut n: usize, width: NonZero<u32>) -> impl Iterator<Item = usize> {
-        let pow = 10usize.pow(width.get() as u32);
+        let pow = 10usize.pow(width.get());
         std::iter::from_fn(move || {
             let window = n % pow;
             n /= pow;
@@ -57,7 +62,7 @@ pub fn part2(input: &str) -> eyre::Result<usize> {
     fn is_invalid_id(id: usize) -> bool {
         let num_digits = num_digits(id) as u32;
         (1..=num_digits / 2).any(|width| {
-            num_digits % width == 0
+            num_digits.is_multiple_of(width)
                 && digit_chunks(id, NonZero::new(width).unwrap())
                     .unique()
                     .count()
@@ -65,26 +70,37 @@ pub fn part2(input: &str) -> eyre::Result<usize> {
         })
     }
 
-    let (_, pairs) = parse(input).map_err(|e| eyre::format_err!("{e}"))?;
-    Ok(pairs
-        .into_iter()
-        .flat_map(|r| r)
+    Ok(ranges
+        .iter()
+        .cloned()
+        .flatten()
         .filter(|u| is_invalid_id(*u))
         .sum::<usize>())
 }
 
-#[cfg(test)]
-mod tests {
-    const INPUT: &str = "11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,446443-446449,38593856-38593862,565653-565659,824824821-824824827,2121212118-2121212124";
+pub struct Day;
+
+impl aoc_util::Solution for Day {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 2;
+    const TITLE: &'static str = "Gift Shop";
+
+    type Input<'a> = Vec<RangeInclusive<usize>>;
+    type Params = ();
 
-    #[test]
-    fn part1_works() -> eyre::Result<()> {
-        assert_eq!(super::part1(INPUT)?, 1227775554);
-        Ok(())
+    fn parse(input: &str) -> eyre::Result<Self::Input<'_>> {
+        parse(input)
     }
-    #[test]
-    fn part2_works() -> eyre::Result<()> {
-        assert_eq!(super::part2(INPUT)?, 4174379265);
-        Ok(())
+    fn part1(input: &Self::Input<'_>, _: &Self::Params) -> eyre::Result<impl std::fmt::Display> {
+        part1(input)
     }
+    fn part2(input: &Self::Input<'_>, _: &Self::Params) -> eyre::Result<impl std::fmt::Display> {
+        part2(input)
+    }
+}
+aoc_util::register!(Day);
+
+aoc_util::examples! {
+    Day;
+    example: "11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,446443-446449,38593856-38593862,565653-565659,824824821-824824827,2121212118-2121212124" => (1227775554, 4174379265u64),
 }
@@ -1,14 +1,14 @@
 use itertools::Itertools;
 
-fn parse(input: &str) -> Vec<Vec<u8>> {
-    input
+pub fn parse(input: &str) -> eyre::Result<Vec<Vec<u8>>> {
+    Ok(input
         .lines()
         .map(|line| {
             line.chars()
                 .filter_map(|c| c.to_digit(10).map(|u| u as u8))
                 .collect_vec()
         })
-        .collect_vec()
+        .collect_vec())
 }
 
 fn jolts(bank: &[u8], batteries: usize) -> Option<u64> {
@@ -35,30 +35,39 @@ fn jolts(bank: &[u8], batteries: usize) -> Option<u64> {
     None
 }
 
-pub fn part1(input: &str) -> eyre::Result<u64> {
-    let banks = parse(input);
-    Ok(banks.iter().filter_map(|bank| jolts(&bank, 2)).sum())
+pub fn part1(banks: &[Vec<u8>]) -> eyre::Result<u64> {
+    Ok(banks.iter().filter_map(|bank| jolts(bank, 2)).sum())
 }
-pub fn part2(input: &str) -> eyre::Result<u64> {
-    let banks = parse(input);
-    Ok(banks.iter().filter_map(|bank| jolts(&bank, 12)).sum())
+pub fn part2(banks: &[Vec<u8>]) -> eyre::Result<u64> {
+    Ok(banks.iter().filter_map(|bank| jolts(bank, 12)).sum())
 }
 
-#[cfg(test)]
-mod tests {
-    const INPUT: &str = "811111111111119
-987654321111111
-234234234234278
-818181911112111";
+pub struct Day;
+
+impl aoc_util::Solution for Day {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 3;
+    const TITLE: &'static str = "Lobby";
 
-    #[test]
-    fn part1_works() -> eyre::Result<()> {
-        assert_eq!(super::part1(INPUT)?, 357);
-        Ok(())
+    type Input<'a> = Vec<Vec<u8>>;
+    type Params = ();
+
+    fn parse(input: &str) -> eyre::Result<Self::Input<'_>> {
+        parse(input)
+    }
+    fn part1(input: &Self::Input<'_>, _: &Self::Params) -> eyre::Result<impl std::fmt::Display> {
+        part1(input)
     }
-    #[test]
-    fn part2_works() -> eyre::Result<()> {
-        assert_eq!(super::part2(INPUT)?, 3121910778619);
-        Ok(())
+    fn part2(input: &Self::Input<'_>, _: &Self::Params) -> eyre::Result<impl std::fmt::Display> {
+        part2(input)
     }
 }
+aoc_util::register!(Day);
+
+aoc_util::examples! {
+    Day;
+    example: "811111111111119
+987654321111111
+234234234234278
+818181911112111" => (357, 3121910778619u64),
+}
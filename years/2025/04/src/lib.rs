@@ -16,16 +16,19 @@ fn accessible(grid: &Grid<char>) -> impl Iterator<Item = Position> + use<'_> {
     })
 }
 
-pub fn part1(input: &str) -> eyre::Result<usize> {
-    let grid = Grid::from_str(input)?;
-    Ok(accessible(&grid).count())
+pub fn parse(input: &str) -> eyre::Result<Grid<char>> {
+    Ok(Grid::from_str(input)?)
 }
-pub fn part2(input: &str) -> eyre::Result<u32> {
-    let mut grid = Grid::from_str(input)?;
+
+pub fn part1(grid: &Grid<char>) -> eyre::Result<usize> {
+    Ok(accessible(grid).count())
+}
+pub fn part2(grid: &Grid<char>) -> eyre::Result<u32> {
+    let mut grid = grid.clone();
     let mut total = 0;
     loop {
         let accessible = accessible(&grid).collect_vec();
-        if accessible.len() == 0 {
+        if accessible.is_empty() {
             break;
         }
 
@@ -39,9 +42,31 @@ pub fn part2(input: &str) -> eyre::Result<u32> {
     Ok(total)
 }
 
-#[cfg(test)]
-mod tests {
-    const INPUT: &str = "..@@.@@@@.
+pub struct Day;
+
+impl aoc_util::Solution for Day {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 4;
+    const TITLE: &'static str = "Printing Department";
+
+    type Input<'a> = Grid<char>;
+    type Params = ();
+
+    fn parse(input: &str) -> eyre::Result<Self::Input<'_>> {
+        parse(input)
+    }
+    fn part1(input: &Self::Input<'_>, _: &Self::Params) -> eyre::Result<impl std::fmt::Display> {
+        part1(input)
+    }
+    fn part2(input: &Self::Input<'_>, _: &Self::Params) -> eyre::Result<impl std::fmt::Display> {
+        part2(input)
+    }
+}
+aoc_util::register!(Day);
+
+aoc_util::examples! {
+    Day;
+    example: "..@@.@@@@.
 @@@.@.@.@@
 @@@@@.@.@@
 @.@@@@..@.
@@ -50,16 +75,5 @@ mod tests {
 .@.@.@.@@@
 @.@@@.@@@@
 .@@@@@@@@.
-@.@.@@@.@.";
-
-    #[test]
-    fn part1_works() -> eyre::Result<()> {
-        assert_eq!(super::part1(INPUT)?, 13);
-        Ok(())
-    }
-    #[test]
-    fn part2_works() -> eyre::Result<()> {
-        assert_eq!(super::part2(INPUT)?, 43);
-        Ok(())
-    }
+@.@.@@@.@." => (13, 43),
 }
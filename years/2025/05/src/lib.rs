@@ -1,4 +1,3 @@
-#![feature(new_range_api)]
 use std::range::RangeInclusive;
 
 use aoc_util::parse::nom::{
@@ -12,7 +11,10 @@ use aoc_util::parse::nom::{
     IResult, Parser,
 };
 
-fn parse(input: &str) -> IResult<&str, (Vec<RangeInclusive<u64>>, Vec<u64>)> {
+/// The fresh ranges and the available ingredients
+pub type Database = (Vec<RangeInclusive<u64>>, Vec<u64>);
+
+fn database(input: &str) -> IResult<&str, Database> {
     let ranges = separated_list1(
         newline,
         separated_pair(u64, char('-'), u64).map(|(l, r)| (l..=r).into()),
@@ -22,20 +24,23 @@ fn parse(input: &str) -> IResult<&str, (Vec<RangeInclusive<u64>>, Vec<u64>)> {
     separated_pair(ranges, tag("\n\n"), ingredients).parse(input)
 }
 
-fn is_fresh(ranges: &Vec<RangeInclusive<u64>>, value: u64) -> bool {
+fn is_fresh(ranges: &[RangeInclusive<u64>], value: u64) -> bool {
     ranges.iter().any(|r| r.contains(&value))
 }
 
-pub fn part1(input: &str) -> eyre::Result<usize> {
-    let (_, (ranges, ingredients)) = parse(input).expect("should parse");
+pub fn parse(input: &str) -> eyre::Result<Database> {
+    let (_, database) = database(input).map_err(|e| eyre::format_err!("{e}"))?;
+    Ok(database)
+}
+
+pub fn part1((ranges, ingredients): &Database) -> eyre::Result<usize> {
     Ok(ingredients
-        .into_iter()
-        .filter(|i| is_fresh(&ranges, *i))
+        .iter()
+        .filter(|i| is_fresh(ranges, **i))
         .count())
 }
-pub fn part2(input: &str) -> eyre::Result<u64> {
-    let (_, (mut ranges, _)) = parse(input).expect("should parse");
-
+pub fn part2((ranges, _): &Database) -> eyre::Result<u64> {
+    let mut ranges = ranges.clone();
     ranges.sort_by_key(|r| r.start);
     ranges.reverse();
 
@@ -78,9 +83,31 @@ pub fn part2(input: &str) -> eyre::Result<u64> {
         .sum::<u64>())
 }
 
-#[cfg(test)]
-mod tests {
-    const INPUT: &str = "3-5
+pub struct Day;
+
+impl aoc_util::Solution for Day {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 5;
+    const TITLE: &'static str = "Cafeteria";
+
+    type Input<'a> = Database;
+    type Params = ();
+
+    fn parse(input: &str) -> eyre::Result<Self::Input<'_>> {
+        parse(input)
+    }
+    fn part1(input: &Self::Input<'_>, _: &Self::Params) -> eyre::Result<impl std::fmt::Display> {
+        part1(input)
+    }
+    fn part2(input: &Self::Input<'_>, _: &Self::Params) -> eyre::Result<impl std::fmt::Display> {
+        part2(input)
+    }
+}
+aoc_util::register!(Day);
+
+aoc_util::examples! {
+    Day;
+    example: "3-5
 10-14
 16-20
 12-18
@@ -90,16 +117,5 @@ mod tests {
 8
 11
 17
-32";
-
-    #[test]
-    fn part1_works() -> eyre::Result<()> {
-        assert_eq!(super::part1(INPUT)?, 3);
-        Ok(())
-    }
-    #[test]
-    fn part2_works() -> eyre::Result<()> {
-        assert_eq!(super::part2(INPUT)?, 14);
-        Ok(())
-    }
+32" => (3, 14),
 }
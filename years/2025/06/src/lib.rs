@@ -2,7 +2,7 @@ use aoc_util::{grid::Grid, math::num_from_iter};
 use itertools::Itertools;
 
 #[derive(Debug)]
-struct Operation {
+pub struct Operation {
     numbers: Grid<Option<u8>>,
     operation: char,
 }
@@ -39,12 +39,14 @@ impl Operation {
     }
 }
 
-fn parse(mut input: &str) -> Vec<Operation> {
+pub fn parse(mut input: &str) -> eyre::Result<Vec<Operation>> {
     input = input.trim_matches('\n');
 
     // operation is always left-aligned, so we can rely on
     // the gaps between them to determine the column widths
-    let last_line_start = input.rfind('\n').expect("should have more than one line");
+    let last_line_start = input
+        .rfind('\n')
+        .ok_or_else(|| eyre::format_err!("should have more than one line"))?;
     let mut operations_line = &input[last_line_start + 1..];
 
     let is_operation = |c| c == '+' || c == '*';
@@ -85,44 +87,52 @@ fn parse(mut input: &str) -> Vec<Operation> {
 
             operands[i]
                 .add_row(cells)
-                .expect("column width should be consistent");
+                .map_err(|e| eyre::format_err!("column width should be consistent: {e:?}"))?;
 
             cursor += width + 1;
         }
     }
 
-    columns
+    Ok(columns
         .into_iter()
         .zip(operands)
-        .into_iter()
         .map(|((operation, _), numbers)| Operation { numbers, operation })
-        .collect_vec()
+        .collect_vec())
 }
 
-pub fn part1(input: &str) -> eyre::Result<u64> {
-    let operations = parse(input);
+pub fn part1(operations: &[Operation]) -> eyre::Result<u64> {
     Ok(operations.iter().map(Operation::human_result).sum())
 }
-pub fn part2(input: &str) -> eyre::Result<u64> {
-    let operations = parse(input);
+pub fn part2(operations: &[Operation]) -> eyre::Result<u64> {
     Ok(operations.iter().map(Operation::cephalopod_result).sum())
 }
 
-#[cfg(test)]
-mod tests {
-    const INPUT: &str = "123 328  51 64 
- 45 64  387 23 
-  6 98  215 314
-*   +   *   +  ";
+pub struct Day;
+
+impl aoc_util::Solution for Day {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 6;
+    const TITLE: &'static str = "Trash Compactor";
+
+    type Input<'a> = Vec<Operation>;
+    type Params = ();
 
-    #[test]
-    fn part1_works() -> eyre::Result<()> {
-        assert_eq!(super::part1(INPUT)?, 4277556);
-        Ok(())
+    fn parse(input: &str) -> eyre::Result<Self::Input<'_>> {
+        parse(input)
     }
-    #[test]
-    fn part2_works() -> eyre::Result<()> {
-        assert_eq!(super::part2(INPUT)?, 3263827);
-        Ok(())
+    fn part1(input: &Self::Input<'_>, _: &Self::Params) -> eyre::Result<impl std::fmt::Display> {
+        part1(input)
     }
+    fn part2(input: &Self::Input<'_>, _: &Self::Params) -> eyre::Result<impl std::fmt::Display> {
+        part2(input)
+    }
+}
+aoc_util::register!(Day);
+
+aoc_util::examples! {
+    Day;
+    example: "123 328  51 64 
+ 45 64  387 23 
+  6 98  215 314
+*   +   *   +  " => (4277556, 3263827),
 }
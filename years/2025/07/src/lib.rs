@@ -47,24 +47,48 @@ fn count_timelines(grid: &Grid<char>, from: Position) -> usize {
     recurse(grid, from, &mut seen)
 }
 
-pub fn part1(input: &str) -> eyre::Result<usize> {
-    let grid = Grid::<char>::from_str(input)?;
+pub fn parse(input: &str) -> eyre::Result<Grid<char>> {
+    Ok(Grid::<char>::from_str(input)?)
+}
+
+pub fn part1(grid: &Grid<char>) -> eyre::Result<usize> {
     let start = grid
         .position_of(&'S')
         .ok_or(eyre::format_err!("no start found"))?;
-    Ok(count_splits(&grid, start))
+    Ok(count_splits(grid, start))
 }
-pub fn part2(input: &str) -> eyre::Result<usize> {
-    let grid = Grid::<char>::from_str(input)?;
+pub fn part2(grid: &Grid<char>) -> eyre::Result<usize> {
     let start = grid
         .position_of(&'S')
         .ok_or(eyre::format_err!("no start found"))?;
-    Ok(count_timelines(&grid, start))
+    Ok(count_timelines(grid, start))
 }
 
-#[cfg(test)]
-mod tests {
-    const INPUT: &str = ".......S.......
+pub struct Day;
+
+impl aoc_util::Solution for Day {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 7;
+    const TITLE: &'static str = "Laboratories";
+
+    type Input<'a> = Grid<char>;
+    type Params = ();
+
+    fn parse(input: &str) -> eyre::Result<Self::Input<'_>> {
+        parse(input)
+    }
+    fn part1(input: &Self::Input<'_>, _: &Self::Params) -> eyre::Result<impl std::fmt::Display> {
+        part1(input)
+    }
+    fn part2(input: &Self::Input<'_>, _: &Self::Params) -> eyre::Result<impl std::fmt::Display> {
+        part2(input)
+    }
+}
+aoc_util::register!(Day);
+
+aoc_util::examples! {
+    Day;
+    example: ".......S.......
 ...............
 .......^.......
 ...............
@@ -79,16 +103,5 @@ mod tests {
 ..^...^.....^..
 ...............
 .^.^.^.^.^...^.
-...............";
-
-    #[test]
-    fn part1_works() -> eyre::Result<()> {
-        assert_eq!(super::part1(INPUT)?, 21);
-        Ok(())
-    }
-    #[test]
-    fn part2_works() -> eyre::Result<()> {
-        assert_eq!(super::part2(INPUT)?, 40);
-        Ok(())
-    }
+..............." => (21, 40),
 }
@@ -14,7 +14,7 @@ use itertools::Itertools;
 
 type Vec3 = euclid::default::Vector3D<i64>;
 
-fn parse(input: &str) -> IResult<&str, Vec<Vec3>> {
+fn junction_boxes(input: &str) -> IResult<&str, Vec<Vec3>> {
     separated_list1(
         newline,
         separated_list1(tag(","), i64).map(|v| {
@@ -26,7 +26,7 @@ fn parse(input: &str) -> IResult<&str, Vec<Vec3>> {
 }
 
 pub fn circuits(
-    positions: &Vec<Vec3>,
+    positions: &[Vec3],
     mut monitor: impl FnMut(usize, (Vec3, Vec3), &Vec<HashSet<Vec3>>) -> ControlFlow<()>,
 ) -> eyre::Result<Vec<HashSet<Vec3>>> {
     let position_pairs = positions
@@ -43,7 +43,7 @@ pub fn circuits(
     let mut iterations = 0;
     for (a, b) in position_pairs.into_iter() {
         // return the circuit index and circuit containing the given vector
-        fn find_circuit_containing(v: &Vec3, circuits: &Vec<HashSet<Vec3>>) -> Option<usize> {
+        fn find_circuit_containing(v: &Vec3, circuits: &[HashSet<Vec3>]) -> Option<usize> {
             circuits.iter().position(|c| c.contains(v))
         }
 
@@ -73,27 +73,33 @@ pub fn circuits(
     Ok(circuits)
 }
 
-pub fn part1(input: &str) -> eyre::Result<u32> {
-    #[cfg(test)]
-    const ITERATIONS: usize = 10;
-    #[cfg(not(test))]
-    const ITERATIONS: usize = 1000;
+pub fn parse(input: &str) -> eyre::Result<Vec<Vec3>> {
+    let (_, positions) = junction_boxes(input).map_err(|e| eyre::format_err!("parse error: {e}"))?;
+    Ok(positions)
+}
 
-    let (_, positions) = parse(input).map_err(|e| eyre::format_err!("parse error: {e}"))?;
+aoc_util::params! {
+    pub struct Params {
+        /// Pairs of closest junction boxes to connect in part 1
+        connections: usize = 1000,
+    }
+}
 
-    let mut circuits = circuits(&positions, |iterations, _, _| match iterations {
-        ITERATIONS => ControlFlow::Break(()),
-        _ => ControlFlow::Continue(()),
+pub fn part1(positions: &[Vec3], params: &Params) -> eyre::Result<u32> {
+    let mut circuits = circuits(positions, |iterations, _, _| {
+        if iterations == params.connections {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
     })?;
 
     circuits.sort_by(|a, b| a.len().cmp(&b.len()).reverse());
     Ok(circuits.iter().take(3).map(|c| c.len() as u32).product())
 }
-pub fn part2(input: &str) -> eyre::Result<i64> {
-    let (_, positions) = parse(input).map_err(|e| eyre::format_err!("parse error: {e}"))?;
-
+pub fn part2(positions: &[Vec3]) -> eyre::Result<i64> {
     let mut answer = None;
-    circuits(&positions, |_, pair, circuits| {
+    circuits(positions, |_, pair, circuits| {
         if circuits.len() == 1 {
             answer = Some(pair.0.x * pair.1.x);
             return ControlFlow::Break(());
@@ -102,12 +108,34 @@ pub fn part2(input: &str) -> eyre::Result<i64> {
         ControlFlow::Continue(())
     })?;
 
-    Ok(answer.wrap_err("no answer found")?)
+    answer.wrap_err("no answer found")
+}
+
+pub struct Day;
+
+impl aoc_util::Solution for Day {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 8;
+    const TITLE: &'static str = "Playground";
+
+    type Input<'a> = Vec<Vec3>;
+    type Params = Params;
+
+    fn parse(input: &str) -> eyre::Result<Self::Input<'_>> {
+        parse(input)
+    }
+    fn part1(input: &Self::Input<'_>, params: &Params) -> eyre::Result<impl std::fmt::Display> {
+        part1(input, params)
+    }
+    fn part2(input: &Self::Input<'_>, _: &Self::Params) -> eyre::Result<impl std::fmt::Display> {
+        part2(input)
+    }
 }
+aoc_util::register!(Day);
 
-#[cfg(test)]
-mod tests {
-    const INPUT: &str = "162,817,812
+aoc_util::examples! {
+    Day;
+    example { connections: 10 }: "162,817,812
 57,618,57
 906,360,560
 592,479,940
@@ -126,16 +154,5 @@ mod tests {
 941,993,340
 862,61,35
 984,92,344
-425,690,689";
-
-    #[test]
-    fn part1_works() -> eyre::Result<()> {
-        assert_eq!(super::part1(INPUT)?, 40);
-        Ok(())
-    }
-    #[test]
-    fn part2_works() -> eyre::Result<()> {
-        assert_eq!(super::part2(INPUT)?, 25272);
-        Ok(())
-    }
+425,690,689" => (40, 25272),
 }
@@ -12,7 +12,7 @@ use itertools::Itertools;
 
 type Vec2 = euclid::default::Point2D<i64>;
 
-fn parse(input: &str) -> IResult<&str, Vec<Vec2>> {
+fn red_tiles(input: &str) -> IResult<&str, Vec<Vec2>> {
     separated_list1(
         newline,
         separated_pair(i64, tag(","), i64).map(|(a, b)| euclid::point2(a, b)),
@@ -20,9 +20,12 @@ fn parse(input: &str) -> IResult<&str, Vec<Vec2>> {
     .parse(input)
 }
 
-pub fn part1(input: &str) -> eyre::Result<i64> {
-    let (_, positions) = parse(input).map_err(|e| eyre::format_err!("parse error: {e}"))?;
+pub fn parse(input: &str) -> eyre::Result<Vec<Vec2>> {
+    let (_, positions) = red_tiles(input).map_err(|e| eyre::format_err!("parse error: {e}"))?;
+    Ok(positions)
+}
 
+pub fn part1(positions: &[Vec2]) -> eyre::Result<i64> {
     Ok(positions
         .iter()
         .tuple_combinations()
@@ -36,9 +39,7 @@ pub fn part1(input: &str) -> eyre::Result<i64> {
         .max()
         .unwrap())
 }
-pub fn part2(input: &str) -> eyre::Result<i64> {
-    let (_, positions) = parse(input).map_err(|e| eyre::format_err!("parse error: {e}"))?;
-
+pub fn part2(positions: &[Vec2]) -> eyre::Result<i64> {
     // loops over triplets, looking for concave vs convex corners
     // TODO: this assumes that you can only build rectangles from these
     // triplets, but that might not be true!
@@ -60,25 +61,36 @@ pub fn part2(input: &str) -> eyre::Result<i64> {
         .unwrap())
 }
 
-#[cfg(test)]
-mod tests {
-    const INPUT: &str = "7,1
+pub struct Day;
+
+impl aoc_util::Solution for Day {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 9;
+    const TITLE: &'static str = "Movie Theater";
+
+    type Input<'a> = Vec<Vec2>;
+    type Params = ();
+
+    fn parse(input: &str) -> eyre::Result<Self::Input<'_>> {
+        parse(input)
+    }
+    fn part1(input: &Self::Input<'_>, _: &Self::Params) -> eyre::Result<impl std::fmt::Display> {
+        part1(input)
+    }
+    fn part2(input: &Self::Input<'_>, _: &Self::Params) -> eyre::Result<impl std::fmt::Display> {
+        part2(input)
+    }
+}
+aoc_util::register!(Day);
+
+aoc_util::examples! {
+    Day;
+    example: "7,1
 11,1
 11,7
 9,7
 9,5
 2,5
 2,3
-7,3";
-
-    #[test]
-    fn part1_works() -> eyre::Result<()> {
-        assert_eq!(super::part1(INPUT)?, 50);
-        Ok(())
-    }
-    #[test]
-    fn part2_works() -> eyre::Result<()> {
-        assert_eq!(super::part2(INPUT)?, 24);
-        Ok(())
-    }
+7,3" => (50, 24),
 }